@@ -0,0 +1,501 @@
+use core::{borrow::Borrow, hash::Hash, ops::Index};
+use std::collections::{hash_map, HashMap};
+
+use crate::map::{self, VecMap};
+
+#[cfg(test)]
+mod tests;
+
+///The default number of entries above which an [`AdaptiveMap`] switches to a [`HashMap`]
+pub const DEFAULT_THRESHOLD: usize = 32;
+
+///A map that starts as a [`VecMap`] and migrates to a [`HashMap`] once it holds more entries than its threshold.
+///
+///Small maps get the cheap linear search and compact storage of [`VecMap`], while large ones keep the constant time
+///lookups of [`HashMap`]. Created with [`AdaptiveMap::with_thresholds`], the map also migrates back to a [`VecMap`] once
+///it shrinks to a lower threshold, the gap between the two keeps a map hovering around a size from migrating on every
+///insertion and removal.
+///
+///While the map is a [`VecMap`] it iterates in insertion order, once it has migrated to a [`HashMap`] the iteration
+///order is unspecified, and it stays unspecified after migrating back.
+#[derive(Clone)]
+pub struct AdaptiveMap<K, V> {
+    repr: Repr<VecMap<K, V>, HashMap<K, V>>,
+    threshold: usize,
+    shrink_threshold: Option<usize>,
+}
+
+#[derive(Clone)]
+enum Repr<A, B> {
+    Vec(A),
+    Hash(B),
+}
+
+impl<K: Eq, V> Default for AdaptiveMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug> core::fmt::Debug for AdaptiveMap<K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.repr {
+            Repr::Vec(map) => map.fmt(f),
+            Repr::Hash(map) => map.fmt(f),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: PartialEq> PartialEq for AdaptiveMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(k, v)| other.get(k).is_some_and(|i| i == v))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq> Eq for AdaptiveMap<K, V> {}
+
+impl<K: Eq, V> AdaptiveMap<K, V> {
+    ///Creates an empty map that migrates to a [`HashMap`] above [`DEFAULT_THRESHOLD`] entries and never migrates back
+    pub const fn new() -> Self {
+        Self::with_threshold(DEFAULT_THRESHOLD)
+    }
+
+    ///Creates an empty map that migrates to a [`HashMap`] above `threshold` entries and never migrates back
+    pub const fn with_threshold(threshold: usize) -> Self {
+        Self {
+            repr: Repr::Vec(VecMap::new()),
+            threshold,
+            shrink_threshold: None,
+        }
+    }
+
+    ///Creates an empty map that migrates to a [`HashMap`] above `threshold` entries, and back to a [`VecMap`] once it
+    ///holds `shrink_threshold` entries or fewer.
+    ///
+    ///# Panics
+    ///
+    ///Panics if `shrink_threshold` isn't lower than `threshold`
+    pub const fn with_thresholds(threshold: usize, shrink_threshold: usize) -> Self {
+        assert!(
+            shrink_threshold < threshold,
+            "the shrink threshold must be lower than the threshold"
+        );
+        Self {
+            repr: Repr::Vec(VecMap::new()),
+            threshold,
+            shrink_threshold: Some(shrink_threshold),
+        }
+    }
+
+    ///Returns the number of entries above which the map migrates to a [`HashMap`]
+    pub const fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    ///Returns the number of entries at or below which the map migrates back to a [`VecMap`], if it does
+    pub const fn shrink_threshold(&self) -> Option<usize> {
+        self.shrink_threshold
+    }
+
+    ///Returns `true` if the entries are currently stored in a [`HashMap`]
+    pub const fn is_hashed(&self) -> bool {
+        matches!(self.repr, Repr::Hash(_))
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Vec(map) => map.len(),
+            Repr::Hash(map) => map.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: match &self.repr {
+                Repr::Vec(map) => Repr::Vec(map.iter()),
+                Repr::Hash(map) => Repr::Hash(map.iter()),
+            },
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: match &mut self.repr {
+                Repr::Vec(map) => Repr::Vec(map.iter_mut()),
+                Repr::Hash(map) => Repr::Hash(map.iter_mut()),
+            },
+        }
+    }
+
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys {
+            inner: match &self.repr {
+                Repr::Vec(map) => Repr::Vec(map.keys()),
+                Repr::Hash(map) => Repr::Hash(map.keys()),
+            },
+        }
+    }
+
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values {
+            inner: match &self.repr {
+                Repr::Vec(map) => Repr::Vec(map.values()),
+                Repr::Hash(map) => Repr::Hash(map.values()),
+            },
+        }
+    }
+
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            inner: match &mut self.repr {
+                Repr::Vec(map) => Repr::Vec(map.values_mut()),
+                Repr::Hash(map) => Repr::Hash(map.values_mut()),
+            },
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> AdaptiveMap<K, V> {
+    fn grow_if_needed(&mut self) {
+        if let Repr::Vec(map) = &mut self.repr {
+            if map.len() > self.threshold {
+                self.repr = Repr::Hash(core::mem::take(map).into());
+            }
+        }
+    }
+
+    fn shrink_if_needed(&mut self) {
+        if let (Repr::Hash(map), Some(shrink_threshold)) = (&mut self.repr, self.shrink_threshold) {
+            if map.len() <= shrink_threshold {
+                self.repr = Repr::Vec(core::mem::take(map).into());
+            }
+        }
+    }
+
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        let old = match &mut self.repr {
+            Repr::Vec(map) => map.insert(k, v),
+            Repr::Hash(map) => map.insert(k, v),
+        };
+        self.grow_if_needed();
+        old
+    }
+
+    pub fn get<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match &self.repr {
+            Repr::Vec(map) => map.get(k),
+            Repr::Hash(map) => map.get(k),
+        }
+    }
+
+    pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match &mut self.repr {
+            Repr::Vec(map) => map.get_mut(k),
+            Repr::Hash(map) => map.get_mut(k),
+        }
+    }
+
+    pub fn get_key_value<Q>(&self, k: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match &self.repr {
+            Repr::Vec(map) => map.get_key_value(k),
+            Repr::Hash(map) => map.get_key_value(k),
+        }
+    }
+
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match &self.repr {
+            Repr::Vec(map) => map.contains_key(k),
+            Repr::Hash(map) => map.contains_key(k),
+        }
+    }
+
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(k).map(|(_, v)| v)
+    }
+
+    pub fn remove_entry<Q>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let removed = match &mut self.repr {
+            Repr::Vec(map) => map.remove_entry(k),
+            Repr::Hash(map) => map.remove_entry(k),
+        };
+        self.shrink_if_needed();
+        removed
+    }
+
+    ///Gets the entry of the key for in-place manipulation.
+    ///
+    ///If inserting into a vacant entry would take the map above its threshold, the map migrates to a [`HashMap`]
+    ///before the entry is returned, even if the entry ends up not being inserted
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        if let Repr::Vec(map) = &mut self.repr {
+            if map.len() >= self.threshold && !map.contains_key(&key) {
+                self.repr = Repr::Hash(core::mem::take(map).into());
+            }
+        }
+        Entry {
+            inner: match &mut self.repr {
+                Repr::Vec(map) => Repr::Vec(map.entry(key)),
+                Repr::Hash(map) => Repr::Hash(map.entry(key)),
+            },
+        }
+    }
+
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        match &mut self.repr {
+            Repr::Vec(map) => map.retain(f),
+            Repr::Hash(map) => map.retain(f),
+        }
+        self.shrink_if_needed();
+    }
+
+    pub fn clear(&mut self) {
+        match &mut self.repr {
+            Repr::Vec(map) => map.clear(),
+            Repr::Hash(map) => map.clear(),
+        }
+        self.shrink_if_needed();
+    }
+}
+
+///A view into a single entry in an [`AdaptiveMap`], created by [`AdaptiveMap::entry`]
+pub struct Entry<'a, K: Eq, V> {
+    inner: Repr<map::Entry<'a, K, V>, hash_map::Entry<'a, K, V>>,
+}
+
+impl<'a, K: Eq, V> Entry<'a, K, V> {
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self.inner {
+            Repr::Vec(e) => e.or_insert(default),
+            Repr::Hash(e) => e.or_insert(default),
+        }
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self.inner {
+            Repr::Vec(e) => e.or_insert_with(default),
+            Repr::Hash(e) => e.or_insert_with(default),
+        }
+    }
+
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self.inner {
+            Repr::Vec(e) => e.or_insert_with_key(default),
+            Repr::Hash(e) => e.or_insert_with_key(default),
+        }
+    }
+
+    pub fn key(&self) -> &K {
+        match &self.inner {
+            Repr::Vec(e) => e.key(),
+            Repr::Hash(e) => e.key(),
+        }
+    }
+
+    #[must_use]
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        Self {
+            inner: match self.inner {
+                Repr::Vec(e) => Repr::Vec(e.and_modify(f)),
+                Repr::Hash(e) => Repr::Hash(e.and_modify(f)),
+            },
+        }
+    }
+}
+
+impl<'a, K: Eq, V: Default> Entry<'a, K, V> {
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert(V::default())
+    }
+}
+
+impl<K: Eq + core::fmt::Debug, V> core::fmt::Debug for Entry<'_, K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Entry").field(self.key()).finish()
+    }
+}
+
+//Every iterator wraps the matching iterators of `VecMap` and `HashMap`, and forwards to whichever one is in use
+macro_rules! adaptive_iter {
+    ($(#[$attr:meta])* $name:ident<$($lt:lifetime,)? K, V>, $item:ty, $vec:ty, $hash:ty) => {
+        $(#[$attr])*
+        pub struct $name<$($lt,)? K, V> {
+            inner: Repr<$vec, $hash>,
+        }
+
+        impl<$($lt,)? K: Eq, V> Iterator for $name<$($lt,)? K, V> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                match &mut self.inner {
+                    Repr::Vec(iter) => iter.next(),
+                    Repr::Hash(iter) => iter.next(),
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match &self.inner {
+                    Repr::Vec(iter) => iter.size_hint(),
+                    Repr::Hash(iter) => iter.size_hint(),
+                }
+            }
+        }
+
+        impl<$($lt,)? K: Eq, V> ExactSizeIterator for $name<$($lt,)? K, V> {
+            fn len(&self) -> usize {
+                match &self.inner {
+                    Repr::Vec(iter) => iter.len(),
+                    Repr::Hash(iter) => iter.len(),
+                }
+            }
+        }
+    };
+}
+
+adaptive_iter!(
+    ///An iterator over the entries of an [`AdaptiveMap`], created by [`AdaptiveMap::iter`]
+    Iter<'a, K, V>,
+    (&'a K, &'a V),
+    map::Iter<'a, K, V>,
+    hash_map::Iter<'a, K, V>
+);
+adaptive_iter!(
+    ///A mutable iterator over the entries of an [`AdaptiveMap`], created by [`AdaptiveMap::iter_mut`]
+    IterMut<'a, K, V>,
+    (&'a K, &'a mut V),
+    map::IterMut<'a, K, V>,
+    hash_map::IterMut<'a, K, V>
+);
+adaptive_iter!(
+    ///An iterator over the keys of an [`AdaptiveMap`], created by [`AdaptiveMap::keys`]
+    Keys<'a, K, V>,
+    &'a K,
+    map::Keys<'a, K, V>,
+    hash_map::Keys<'a, K, V>
+);
+adaptive_iter!(
+    ///An iterator over the values of an [`AdaptiveMap`], created by [`AdaptiveMap::values`]
+    Values<'a, K, V>,
+    &'a V,
+    map::Values<'a, K, V>,
+    hash_map::Values<'a, K, V>
+);
+adaptive_iter!(
+    ///A mutable iterator over the values of an [`AdaptiveMap`], created by [`AdaptiveMap::values_mut`]
+    ValuesMut<'a, K, V>,
+    &'a mut V,
+    map::ValuesMut<'a, K, V>,
+    hash_map::ValuesMut<'a, K, V>
+);
+adaptive_iter!(
+    ///An owning iterator over the entries of an [`AdaptiveMap`]
+    IntoIter<K, V>,
+    (K, V),
+    map::IntoIter<K, V>,
+    hash_map::IntoIter<K, V>
+);
+
+impl<K: Eq, V> IntoIterator for AdaptiveMap<K, V> {
+    type Item = (K, V);
+
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: match self.repr {
+                Repr::Vec(map) => Repr::Vec(map.into_iter()),
+                Repr::Hash(map) => Repr::Hash(map.into_iter()),
+            },
+        }
+    }
+}
+
+impl<'a, K: Eq, V> IntoIterator for &'a AdaptiveMap<K, V> {
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: Eq, V> IntoIterator for &'a mut AdaptiveMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: Hash + Eq, V> Extend<(K, V)> for AdaptiveMap<K, V> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> FromIterator<(K, V)> for AdaptiveMap<K, V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Hash + Eq, V, const N: usize> From<[(K, V); N]> for AdaptiveMap<K, V> {
+    fn from(arr: [(K, V); N]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<K, Q, V> Index<&Q> for AdaptiveMap<K, V>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("no entry found for key")
+    }
+}
@@ -0,0 +1,109 @@
+use std::{string::String, vec::Vec};
+
+use crate::adaptive_map::*;
+
+#[test]
+fn test_grow() {
+    let mut map = AdaptiveMap::with_threshold(3);
+    for i in 0..3 {
+        assert_eq!(map.insert(i, i * 10), None);
+    }
+    assert!(!map.is_hashed());
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+
+    // Overwriting doesn't change the length
+    assert_eq!(map.insert(2, 25), Some(20));
+    assert!(!map.is_hashed());
+
+    map.insert(3, 30);
+    assert!(map.is_hashed());
+    assert_eq!(map.len(), 4);
+    assert_eq!(map.get(&2), Some(&25));
+    assert_eq!(map[&3], 30);
+
+    // Without a shrink threshold the map stays hashed
+    map.clear();
+    assert!(map.is_hashed());
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_shrink() {
+    let mut map: AdaptiveMap<i32, i32> = AdaptiveMap::with_thresholds(4, 2);
+    map.extend((0..5).map(|i| (i, i)));
+    assert!(map.is_hashed());
+
+    assert_eq!(map.remove(&0), Some(0));
+    assert_eq!(map.remove_entry(&1), Some((1, 1)));
+    assert!(map.is_hashed());
+    assert_eq!(map.remove(&2), Some(2));
+    assert!(!map.is_hashed());
+
+    let mut values = map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>();
+    values.sort_unstable();
+    assert_eq!(values, vec![(3, 3), (4, 4)]);
+
+    map.extend((0..5).map(|i| (i, i)));
+    assert!(map.is_hashed());
+    map.retain(|k, _| *k % 2 == 0);
+    assert!(map.is_hashed());
+    map.retain(|k, _| *k < 4);
+    assert!(!map.is_hashed());
+    assert_eq!(map.len(), 2);
+}
+
+#[test]
+#[should_panic = "the shrink threshold must be lower than the threshold"]
+fn test_invalid_thresholds() {
+    let _ = AdaptiveMap::<i32, i32>::with_thresholds(2, 2);
+}
+
+#[test]
+fn test_entry() {
+    let mut map = AdaptiveMap::with_threshold(2);
+    *map.entry(String::from("a")).or_insert(0) += 1;
+    *map.entry(String::from("a")).or_insert(0) += 1;
+    *map.entry(String::from("b")).or_default() += 5;
+    assert!(!map.is_hashed());
+
+    // An existing key doesn't migrate the map
+    map.entry(String::from("b"))
+        .and_modify(|v| *v += 1)
+        .or_insert(0);
+    assert!(!map.is_hashed());
+
+    let v = map
+        .entry(String::from("c"))
+        .or_insert_with_key(|k| k.len() as i32);
+    *v += 1;
+    assert!(map.is_hashed());
+    assert_eq!(map.get("a"), Some(&2));
+    assert_eq!(map.get("b"), Some(&6));
+    assert_eq!(map.get("c"), Some(&2));
+    assert_eq!(map.entry(String::from("c")).key(), "c");
+}
+
+#[test]
+fn test_iter() {
+    for threshold in [1, 10] {
+        let mut map = AdaptiveMap::with_threshold(threshold);
+        map.extend([(1, 1), (2, 2), (3, 3)]);
+        assert_eq!(map.is_hashed(), threshold == 1);
+
+        for (_, v) in &mut map {
+            *v *= 2;
+        }
+        for v in map.values_mut() {
+            *v += 1;
+        }
+        assert_eq!(map.iter().len(), 3);
+        assert_eq!(map.values().sum::<i32>(), 15);
+        assert!(map.contains_key(&1));
+        assert_eq!(map.get_key_value(&2), Some((&2, &5)));
+
+        let mut entries = map.clone().into_iter().collect::<Vec<_>>();
+        entries.sort_unstable();
+        assert_eq!(entries, vec![(1, 3), (2, 5), (3, 7)]);
+        assert_eq!(map, AdaptiveMap::from([(3, 7), (2, 5), (1, 3)]));
+    }
+}
@@ -0,0 +1,37 @@
+use core::marker::PhantomData;
+
+use arrayvec::ArrayVec;
+
+use crate::map::VecMap;
+pub use crate::storage::CapacityError;
+
+///A [`VecMap`] that stores up to `N` entries inline instead of on the heap.
+///
+///All the lookup, entry and iteration methods are shared with [`VecMap`]. Inserting a new key into a full map fails:
+///[`VecMap::try_insert`], [`crate::map::VaccantEntrty::try_insert`] and [`crate::map::Entry::or_try_insert`] return
+///a [`CapacityError`] holding the rejected entry, while the infallible methods panic. Create one with
+///[`ArrayVecMap::new_const`], [`Default::default`] or [`FromIterator`].
+pub type ArrayVecMap<K, V, const N: usize> = VecMap<K, V, ArrayVec<(K, V), N>>;
+
+impl<K, V, const N: usize> ArrayVecMap<K, V, N> {
+    ///Creates an empty `ArrayVecMap`, usable in `const` and `static` items
+    pub const fn new_const() -> Self {
+        Self {
+            vec: ArrayVec::new_const(),
+            _marker: PhantomData,
+        }
+    }
+
+    ///Returns `true` if no more entries can be inserted into the map
+    pub const fn is_full(&self) -> bool {
+        self.vec.is_full()
+    }
+
+    ///Returns the number of entries that can still be inserted into the map
+    pub const fn remaining_capacity(&self) -> usize {
+        self.vec.remaining_capacity()
+    }
+}
+
+#[cfg(test)]
+mod tests;
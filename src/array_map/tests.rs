@@ -0,0 +1,127 @@
+use crate::{array_map::*, map::Entry};
+use std::vec::Vec;
+
+#[test]
+fn test_insert_and_get() {
+    let mut m: ArrayVecMap<i32, char, 3> = ArrayVecMap::new_const();
+    assert_eq!(m.capacity(), 3);
+    assert!(m.is_empty());
+
+    assert_eq!(m.insert(1, 'a'), None);
+    assert_eq!(m.insert(2, 'b'), None);
+    assert_eq!(m.insert(1, 'c'), Some('a'));
+    assert_eq!(m.len(), 2);
+    assert_eq!(m.remaining_capacity(), 1);
+    assert_eq!(m[&1], 'c');
+    assert_eq!(m.get(&3), None);
+    assert!(m.contains_key(&2));
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 2]);
+}
+
+#[test]
+fn test_try_insert_full() {
+    let mut m: ArrayVecMap<i32, i32, 2> = [(1, 1), (2, 2)].into_iter().collect();
+    assert!(m.is_full());
+
+    let err = m.try_insert(3, 3).unwrap_err();
+    assert_eq!(err.element(), (3, 3));
+    // Overwriting an existing key doesn't need any space
+    assert_eq!(m.try_insert(2, 20), Ok(Some(2)));
+
+    match m.entry(4) {
+        Entry::Occupied(_) => unreachable!(),
+        Entry::Vacant(e) => assert!(e.try_insert(4).is_err()),
+    }
+    assert!(m.entry(5).or_try_insert(5).is_err());
+    assert_eq!(*m.entry(1).or_try_insert(5).unwrap(), 1);
+    assert_eq!(m.len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_insert_full_panics() {
+    let mut m: ArrayVecMap<i32, i32, 1> = ArrayVecMap::new_const();
+    m.insert(1, 1);
+    m.insert(2, 2);
+}
+
+#[test]
+fn test_remove_and_order() {
+    let mut m: ArrayVecMap<i32, i32, 8> = (0..6).map(|i| (i, i)).collect();
+
+    assert_eq!(m.remove(&1), Some(1));
+    assert_eq!(m.swap_remove(&0), Some(0));
+    assert_eq!(m.remove_entry(&9), None);
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [5, 2, 3, 4]);
+
+    m.retain(|k, _| *k != 3);
+    assert_eq!(m.get_index(1), Some((&2, &2)));
+    assert_eq!(m.get_index_of(&4), Some(2));
+    assert_eq!(m.first(), Some((&5, &5)));
+    assert_eq!(m.pop(), Some((4, 4)));
+    assert_eq!(m.last(), Some((&2, &2)));
+
+    assert_eq!(m.drain().collect::<Vec<_>>(), [(5, 5), (2, 2)]);
+    assert!(m.is_empty());
+}
+
+#[test]
+fn test_entry() {
+    let mut m: ArrayVecMap<&str, i32, 4> = ArrayVecMap::new_const();
+
+    for word in ["a", "b", "a", "c", "a"] {
+        *m.entry(word).or_default() += 1;
+    }
+    assert_eq!(m["a"], 3);
+    assert_eq!(m.entry("c").index(), 2);
+
+    match m.entry("b") {
+        Entry::Vacant(_) => unreachable!(),
+        Entry::Occupied(mut e) => {
+            assert_eq!(e.insert(10), 1);
+            assert_eq!(e.remove_entry(), ("b", 10));
+        }
+    }
+    assert_eq!(m.into_iter().collect::<Vec<_>>(), [("a", 3), ("c", 1)]);
+}
+
+#[test]
+fn test_eq_and_iter() {
+    let mut a: ArrayVecMap<i32, i32, 4> = [(1, 1), (2, 2), (3, 3), (4, 4)].into_iter().collect();
+    let b: ArrayVecMap<i32, i32, 4> = [(4, 4), (3, 3), (2, 2), (1, 1)].into_iter().collect();
+    assert_eq!(a, b);
+
+    for v in a.values_mut() {
+        *v *= 2;
+    }
+    assert_ne!(a, b);
+    assert_eq!(a.iter().map(|(_, v)| *v).sum::<i32>(), 20);
+    assert_eq!(a.clone().into_values().sum::<i32>(), 20);
+    assert_eq!(a.into_keys().sum::<i32>(), 10);
+}
+
+#[test]
+fn test_shared_map_methods() {
+    let mut m: ArrayVecMap<i32, i32, 4> = ArrayVecMap::new_const();
+    for i in 0..4 {
+        m.insert(i, i * 10);
+    }
+    assert!(m.is_full());
+
+    if let Some([a, b]) = m.get_many_mut([&1, &3]) {
+        core::mem::swap(a, b);
+    }
+    assert_eq!(
+        m.extract_if(|k, _| *k % 2 == 0).collect::<Vec<_>>(),
+        [(0, 0), (2, 20)]
+    );
+    assert_eq!(m.remaining_capacity(), 2);
+
+    m.swap_indices(0, 1);
+    assert_eq!(m.iter().collect::<Vec<_>>(), [(&3, &10), (&1, &30)]);
+    assert_eq!(m.swap_remove_index(0), Some((3, 10)));
+    assert_eq!(*m.entry(7).or_insert(70), 70);
+    assert_eq!(m.try_insert(8, 80), Ok(None));
+    assert_eq!(m.try_insert(9, 90), Ok(None));
+    assert_eq!(m.try_insert(10, 100).unwrap_err().element(), (10, 100));
+}
@@ -0,0 +1,36 @@
+use core::marker::PhantomData;
+
+use arrayvec::ArrayVec;
+
+use crate::set::VecSet;
+pub use crate::storage::CapacityError;
+
+///A [`VecSet`] that stores up to `N` values inline instead of on the heap.
+///
+///All the lookup, iteration and set algebra methods are shared with [`VecSet`]. Inserting a new value into a full set
+///fails: [`VecSet::try_insert`] returns a [`CapacityError`] holding the rejected value, while the infallible methods
+///panic. Create one with [`ArrayVecSet::new_const`], [`Default::default`] or [`FromIterator`].
+pub type ArrayVecSet<T, const N: usize> = VecSet<T, ArrayVec<T, N>>;
+
+impl<T, const N: usize> ArrayVecSet<T, N> {
+    ///Creates an empty `ArrayVecSet`, usable in `const` and `static` items
+    pub const fn new_const() -> Self {
+        Self {
+            inner: ArrayVec::new_const(),
+            _marker: PhantomData,
+        }
+    }
+
+    ///Returns `true` if no more values can be inserted into the set
+    pub const fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    ///Returns the number of values that can still be inserted into the set
+    pub const fn remaining_capacity(&self) -> usize {
+        self.inner.remaining_capacity()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,85 @@
+use crate::array_set::*;
+use std::vec::Vec;
+
+#[test]
+fn test_insert_and_contains() {
+    let mut s: ArrayVecSet<i32, 3> = ArrayVecSet::new_const();
+    assert_eq!(s.capacity(), 3);
+
+    assert!(s.insert(1));
+    assert!(s.insert(2));
+    assert!(!s.insert(1));
+    assert_eq!(s.len(), 2);
+    assert!(s.contains(&2));
+    assert!(!s.contains(&3));
+    assert_eq!(s.get(&1), Some(&1));
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), [1, 2]);
+}
+
+#[test]
+fn test_try_insert_full() {
+    let mut s: ArrayVecSet<i32, 2> = [1, 2].into_iter().collect();
+    assert!(s.is_full());
+
+    assert_eq!(s.try_insert(3).unwrap_err().element(), 3);
+    assert_eq!(s.try_insert(2), Ok(false));
+    assert_eq!(s.replace(1), Some(1));
+}
+
+#[test]
+#[should_panic]
+fn test_insert_full_panics() {
+    let mut s: ArrayVecSet<i32, 1> = ArrayVecSet::new_const();
+    s.insert(1);
+    s.insert(2);
+}
+
+#[test]
+fn test_remove_and_order() {
+    let mut s: ArrayVecSet<i32, 8> = (0..6).collect();
+
+    assert!(s.remove(&1));
+    assert!(s.swap_remove(&0));
+    assert!(!s.remove(&9));
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), [5, 2, 3, 4]);
+    assert_eq!(s.take(&3), Some(3));
+
+    s.retain(|v| *v != 4);
+    assert_eq!(s.get_index_of(&2), Some(1));
+    assert_eq!(s.first(), Some(&5));
+    assert_eq!(s.last(), Some(&2));
+    assert_eq!(s.pop(), Some(2));
+    assert_eq!(s.drain().collect::<Vec<_>>(), [5]);
+    assert!(s.is_empty());
+}
+
+#[test]
+fn test_subset_and_eq() {
+    let a: ArrayVecSet<i32, 4> = [1, 2, 3, 4].into_iter().collect();
+    let b: ArrayVecSet<i32, 2> = [3, 1].into_iter().collect();
+    let c: ArrayVecSet<i32, 2> = [5, 6].into_iter().collect();
+
+    assert!(b.is_subset(&a));
+    assert!(a.is_superset(&b));
+    assert!(a.is_disjoint(&c));
+    assert_ne!(c, b);
+    assert_eq!(b, ArrayVecSet::<i32, 2>::from_iter([1, 3]));
+    assert_eq!(format!("{b:?}"), "{3, 1}");
+}
+
+#[test]
+fn test_shared_set_methods() {
+    let a: ArrayVecSet<i32, 4> = [1, 2, 3, 4].into_iter().collect();
+    let b: ArrayVecSet<i32, 4> = [3, 4].into_iter().collect();
+
+    assert_eq!(a.intersection(&b).copied().collect::<Vec<_>>(), [3, 4]);
+    assert_eq!(a.symmetric_difference(&b).count(), 2);
+    assert_eq!(a.difference(&b).copied().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!((&a | &b).remaining_capacity(), 0);
+
+    let mut c = a.clone();
+    assert_eq!(c.extract_if(|v| *v % 2 == 0).collect::<Vec<_>>(), [2, 4]);
+    c.move_index(0, 1);
+    assert_eq!(c.iter().copied().collect::<Vec<_>>(), [3, 1]);
+    assert!(c.try_insert(5).is_ok());
+}
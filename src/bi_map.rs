@@ -0,0 +1,292 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::{
+    equivalent::Equivalent,
+    map::{IntoIter, Iter, Keys, Values},
+};
+
+#[cfg(test)]
+mod tests;
+
+///A bidirectional map where both the left and the right values are unique, using the same `Vec<(L, R)>` layout as
+///[`crate::map::VecMap`].
+///
+///Both sides are looked up with a linear search, so only [`Eq`] is required on them. Like [`crate::map::VecMap`],
+///insertion order is preserved: a pair overwriting existing pairs takes the place of the first one.
+#[derive(Clone)]
+pub struct VecBiMap<L, R> {
+    vec: Vec<(L, R)>,
+}
+
+///The pairs that were removed from a [`VecBiMap`] by [`VecBiMap::insert`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Overwritten<L, R> {
+    ///Neither value was in the map
+    Neither,
+    ///The left value was in the map, paired with another right value
+    Left(L, R),
+    ///The right value was in the map, paired with another left value
+    Right(L, R),
+    ///The exact pair was already in the map
+    Pair(L, R),
+    ///Both values were in the map in two different pairs, the pair of the left value comes first
+    Both((L, R), (L, R)),
+}
+
+impl<L, R> Overwritten<L, R> {
+    ///Returns `true` if the insertion removed at least one pair
+    pub const fn did_overwrite(&self) -> bool {
+        !matches!(self, Overwritten::Neither)
+    }
+}
+
+impl<L, R> Default for VecBiMap<L, R> {
+    fn default() -> Self {
+        Self { vec: Vec::new() }
+    }
+}
+
+impl<L: core::fmt::Debug, R: core::fmt::Debug> core::fmt::Debug for VecBiMap<L, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(self.vec.iter().map(|(l, r)| (l, r)))
+            .finish()
+    }
+}
+
+impl<L: Eq, R: Eq> PartialEq for VecBiMap<L, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(l, r)| other.get_by_left(l).is_some_and(|i| i == r))
+    }
+}
+
+impl<L: Eq, R: Eq> Eq for VecBiMap<L, R> {}
+
+impl<L, R> VecBiMap<L, R> {
+    pub const fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    ///Returns all pairs of the map as a slice
+    pub fn as_slice(&self) -> &[(L, R)] {
+        &self.vec
+    }
+
+    pub fn iter(&self) -> Iter<'_, L, R> {
+        Iter {
+            inner: self.vec.iter(),
+        }
+    }
+
+    ///An iterator over the left values
+    pub fn left_values(&self) -> Keys<'_, L, R> {
+        Keys {
+            inner: self.vec.iter(),
+        }
+    }
+
+    ///An iterator over the right values
+    pub fn right_values(&self) -> Values<'_, L, R> {
+        Values {
+            inner: self.vec.iter(),
+        }
+    }
+
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&L, &R) -> bool,
+    {
+        let mut f = f;
+        self.vec.retain(|i| f(&i.0, &i.1));
+    }
+}
+
+impl<L: Eq, R: Eq> VecBiMap<L, R> {
+    fn position_left<Q>(&self, left: &Q) -> Option<usize>
+    where
+        Q: Equivalent<L> + ?Sized,
+    {
+        self.vec.iter().position(|(l, _)| left.equivalent(l))
+    }
+
+    fn position_right<Q>(&self, right: &Q) -> Option<usize>
+    where
+        Q: Equivalent<R> + ?Sized,
+    {
+        self.vec.iter().position(|(_, r)| right.equivalent(r))
+    }
+
+    ///Inserts the pair, removing any pair that holds either value, and returns the removed pairs
+    pub fn insert(&mut self, left: L, right: R) -> Overwritten<L, R> {
+        let left_index = self.position_left(&left);
+        let right_index = self.position_right(&right);
+        match (left_index, right_index) {
+            (None, None) => {
+                self.vec.push((left, right));
+                Overwritten::Neither
+            }
+            (Some(i), None) => {
+                let (l, r) = core::mem::replace(&mut self.vec[i], (left, right));
+                Overwritten::Left(l, r)
+            }
+            (None, Some(i)) => {
+                let (l, r) = core::mem::replace(&mut self.vec[i], (left, right));
+                Overwritten::Right(l, r)
+            }
+            (Some(i), Some(j)) if i == j => {
+                let (l, r) = core::mem::replace(&mut self.vec[i], (left, right));
+                Overwritten::Pair(l, r)
+            }
+            (Some(i), Some(j)) => {
+                //The new pair takes the place of the first of the two, the other one is removed
+                let (first, second) = (i.min(j), i.max(j));
+                let removed = self.vec.remove(second);
+                let replaced = core::mem::replace(&mut self.vec[first], (left, right));
+                if i < j {
+                    Overwritten::Both(replaced, removed)
+                } else {
+                    Overwritten::Both(removed, replaced)
+                }
+            }
+        }
+    }
+
+    ///Inserts the pair only if neither value is in the map, otherwise the pair is given back
+    pub fn insert_no_overwrite(&mut self, left: L, right: R) -> Result<(), (L, R)> {
+        if self.contains_left(&left) || self.contains_right(&right) {
+            Err((left, right))
+        } else {
+            self.vec.push((left, right));
+            Ok(())
+        }
+    }
+
+    ///Returns the right value paired with the left value
+    pub fn get_by_left<Q>(&self, left: &Q) -> Option<&R>
+    where
+        Q: Equivalent<L> + ?Sized,
+    {
+        self.vec
+            .iter()
+            .find(|(l, _)| left.equivalent(l))
+            .map(|(_, r)| r)
+    }
+
+    ///Returns the left value paired with the right value
+    pub fn get_by_right<Q>(&self, right: &Q) -> Option<&L>
+    where
+        Q: Equivalent<R> + ?Sized,
+    {
+        self.vec
+            .iter()
+            .find(|(_, r)| right.equivalent(r))
+            .map(|(l, _)| l)
+    }
+
+    pub fn contains_left<Q>(&self, left: &Q) -> bool
+    where
+        Q: Equivalent<L> + ?Sized,
+    {
+        self.position_left(left).is_some()
+    }
+
+    pub fn contains_right<Q>(&self, right: &Q) -> bool
+    where
+        Q: Equivalent<R> + ?Sized,
+    {
+        self.position_right(right).is_some()
+    }
+
+    ///Removes the pair holding the left value, shifting all following pairs down by one
+    pub fn remove_by_left<Q>(&mut self, left: &Q) -> Option<(L, R)>
+    where
+        Q: Equivalent<L> + ?Sized,
+    {
+        let index = self.position_left(left)?;
+        Some(self.vec.remove(index))
+    }
+
+    ///Removes the pair holding the right value, shifting all following pairs down by one
+    pub fn remove_by_right<Q>(&mut self, right: &Q) -> Option<(L, R)>
+    where
+        Q: Equivalent<R> + ?Sized,
+    {
+        let index = self.position_right(right)?;
+        Some(self.vec.remove(index))
+    }
+}
+
+impl<L: Eq, R: Eq> FromIterator<(L, R)> for VecBiMap<L, R> {
+    fn from_iter<T: IntoIterator<Item = (L, R)>>(iter: T) -> Self {
+        let mut this = Self::new();
+        this.extend(iter);
+        this
+    }
+}
+
+impl<L: Eq, R: Eq> Extend<(L, R)> for VecBiMap<L, R> {
+    fn extend<T: IntoIterator<Item = (L, R)>>(&mut self, iter: T) {
+        for (l, r) in iter {
+            self.insert(l, r);
+        }
+    }
+}
+
+impl<L: Eq, R: Eq, const N: usize> From<[(L, R); N]> for VecBiMap<L, R> {
+    fn from(arr: [(L, R); N]) -> Self {
+        arr.into_iter().collect()
+    }
+}
+
+impl<L: Eq, R: Eq> IntoIterator for VecBiMap<L, R> {
+    type Item = (L, R);
+
+    type IntoIter = IntoIter<L, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.vec.into_iter(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, L, R> IntoIterator for &'a VecBiMap<L, R> {
+    type Item = (&'a L, &'a R);
+
+    type IntoIter = Iter<'a, L, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
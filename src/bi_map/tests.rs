@@ -0,0 +1,67 @@
+use std::{string::String, vec::Vec};
+
+use crate::bi_map::*;
+
+#[test]
+fn test_lookup() {
+    let map = VecBiMap::from([(1, "one"), (2, "two"), (3, "three")]);
+    assert_eq!(map.get_by_left(&2), Some(&"two"));
+    assert_eq!(map.get_by_right(&"three"), Some(&3));
+    assert_eq!(map.get_by_left(&4), None);
+    assert!(map.contains_left(&1));
+    assert!(!map.contains_right(&"four"));
+    assert_eq!(
+        map.left_values().copied().collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        map.right_values().copied().collect::<Vec<_>>(),
+        vec!["one", "two", "three"]
+    );
+
+    let names: VecBiMap<u32, String> = [(7, String::from("seven"))].into_iter().collect();
+    assert_eq!(names.get_by_right("seven"), Some(&7));
+}
+
+#[test]
+fn test_insert_overwrite() {
+    let mut map = VecBiMap::new();
+    assert_eq!(map.insert(1, 'a'), Overwritten::Neither);
+    assert_eq!(map.insert(2, 'b'), Overwritten::Neither);
+    assert_eq!(map.insert(3, 'c'), Overwritten::Neither);
+
+    assert_eq!(map.insert(1, 'x'), Overwritten::Left(1, 'a'));
+    assert_eq!(map.insert(4, 'b'), Overwritten::Right(2, 'b'));
+    assert_eq!(map.insert(3, 'c'), Overwritten::Pair(3, 'c'));
+    assert_eq!(map.as_slice(), &[(1, 'x'), (4, 'b'), (3, 'c')]);
+
+    assert!(map.insert(1, 'c').did_overwrite());
+    assert_eq!(map.as_slice(), &[(1, 'c'), (4, 'b')]);
+    assert_eq!(map.insert(4, 'c'), Overwritten::Both((4, 'b'), (1, 'c')));
+    assert_eq!(map.as_slice(), &[(4, 'c')]);
+
+    assert_eq!(map.insert_no_overwrite(4, 'z'), Err((4, 'z')));
+    assert_eq!(map.insert_no_overwrite(5, 'z'), Ok(()));
+    assert_eq!(map.len(), 2);
+}
+
+#[test]
+fn test_remove() {
+    let mut map = VecBiMap::from([(1, "one"), (2, "two"), (3, "three")]);
+    assert_eq!(map.remove_by_left(&1), Some((1, "one")));
+    assert_eq!(map.remove_by_left(&1), None);
+    assert_eq!(map.remove_by_right(&"three"), Some((3, "three")));
+    assert_eq!(map.into_iter().collect::<Vec<_>>(), vec![(2, "two")]);
+}
+
+#[test]
+fn test_collect_and_eq() {
+    let map: VecBiMap<_, _> = [(1, 'a'), (2, 'a'), (2, 'b')].into_iter().collect();
+    assert_eq!(map.as_slice(), &[(2, 'b')]);
+
+    let a = VecBiMap::from([(1, 'a'), (2, 'b')]);
+    let mut b = VecBiMap::from([(2, 'b'), (1, 'a')]);
+    assert_eq!(a, b);
+    b.retain(|l, _| *l == 1);
+    assert_ne!(a, b);
+}
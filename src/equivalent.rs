@@ -0,0 +1,21 @@
+use core::borrow::Borrow;
+
+///Key equivalence, used by every lookup in [`crate::map::VecMap`] and [`crate::set::VecSet`] instead of requiring
+///`K: Borrow<Q>`.
+///
+///It's implemented for every `Q` that `K` borrows as, implementing it for other types allows looking up keys
+///without constructing an owned key, for example `(String, u32)` keys by a `(&str, u32)`.
+pub trait Equivalent<K: ?Sized> {
+    ///Returns `true` if `self` is equivalent to `key`
+    fn equivalent(&self, key: &K) -> bool;
+}
+
+impl<Q, K> Equivalent<K> for Q
+where
+    Q: Eq + ?Sized,
+    K: Borrow<Q> + ?Sized,
+{
+    fn equivalent(&self, key: &K) -> bool {
+        *self == *key.borrow()
+    }
+}
@@ -0,0 +1,82 @@
+use core::hash::{Hash, Hasher};
+
+///Feeds the items of an unordered collection into `state`, so that any permutation of the same items produces the
+///same hash.
+///
+///Every item is hashed on its own with a fixed [`FxHasher`], the per-item hashes are then combined with a wrapping
+///sum, which doesn't depend on the order.
+///
+///The caller's hasher can't be forked for every item, so the per-item hashes don't depend on its seed. Collisions
+///between items are the same under every seed, which is documented on the `Hash` impls using this.
+pub(crate) fn hash_unordered<I, H>(items: I, state: &mut H)
+where
+    I: ExactSizeIterator,
+    I::Item: Hash,
+    H: Hasher,
+{
+    state.write_usize(items.len());
+    let mut sum = 0u64;
+    for item in items {
+        let mut hasher = FxHasher::default();
+        item.hash(&mut hasher);
+        sum = sum.wrapping_add(mix(hasher.finish()));
+    }
+    state.write_u64(sum);
+}
+
+///Finalizer of splitmix64, spreads the bits of the per-item hashes before they are summed
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+///The hasher used by rustc, fast and deterministic, which is all that's needed for the per-item hashes
+#[derive(Default)]
+struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(Self::SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add_to_hash(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        for &byte in chunks.remainder() {
+            self.add_to_hash(u64::from(byte));
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(u64::from(i));
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(u64::from(i));
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(u64::from(i));
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
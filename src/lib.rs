@@ -7,14 +7,71 @@
 //!except for functions that interact with the hasher.
 //!
 //!For obvious reasons neither [`map::VecMap`] not [`set::VecSet`] use a hasher.
+//!
+//!Unlike their std counterparts, both [`map::VecMap`] and [`set::VecSet`] preserve insertion order: overwriting an
+//!existing key or value keeps it in place, and removals shift the following elements, so iteration order is
+//!deterministic.
+//!
+//!The crate is `no_std` and only depends on `alloc`. The default `std` feature enables:
+//!- the [`PartialEq`] impls between the maps and sets and [`std::collections::HashMap`] and
+//!  [`std::collections::HashSet`]
+//!- the [`From`] conversions between them
+//!- `AdaptiveMap`, a map that switches to a [`std::collections::HashMap`] as it grows
+//!- the [`std::error::Error`] impl of [`storage::CapacityError`]
+//!
+//!It's also required by the `rayon` feature.
+//!
+//!The optional `arrayvec` and `smallvec` features add `ArrayVecMap`, `ArrayVecSet` and `SmallVecMap`, which store
+//!their elements inline.
 
+#![no_std]
 #![allow(
     clippy::must_use_candidate,
     clippy::missing_panics_doc,
     clippy::unwrap_or_default
 )]
 
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+#[cfg_attr(test, macro_use)]
+extern crate std;
+
+///Contains [`adaptive_map::AdaptiveMap`], a map that migrates from a [`map::VecMap`] to a
+///[`std::collections::HashMap`] as it grows
+#[cfg(feature = "std")]
+pub mod adaptive_map;
+///Contains [`array_map::ArrayVecMap`], a [`map::VecMap`] with a fixed capacity that stores its entries inline
+#[cfg(feature = "arrayvec")]
+pub mod array_map;
+///Contains [`array_set::ArrayVecSet`], a [`set::VecSet`] with a fixed capacity that stores its values inline
+#[cfg(feature = "arrayvec")]
+pub mod array_set;
+///Contains [`bi_map::VecBiMap`], a bidirectional map where both sides are unique
+pub mod bi_map;
+///Contains [`equivalent::Equivalent`], the trait used to compare lookup keys with the stored keys
+pub mod equivalent;
+mod hash;
+///Contains [`lru_cache::VecLruCache`], a fixed capacity least recently used cache built on [`map::VecMap`]
+pub mod lru_cache;
 ///Contains [`map::VecMap`], drop in replacement for [`std::collections::HashMap`]
 pub mod map;
+///Contains [`multi_map::VecMultiMap`], a map that can hold several values for the same key
+pub mod multi_map;
+#[cfg(feature = "rayon")]
+pub mod rayon;
+///Contains [`self_organizing::SelfOrganizingMap`] and [`self_organizing::SelfOrganizingSet`], which reorder their
+///entries on lookups so frequently accessed keys are found sooner
+pub mod self_organizing;
+#[cfg(feature = "serde")]
+pub mod serde;
 ///Contains [`set::VecSet`], drop in replacement for [`std::collections::HashSet`]
 pub mod set;
+///Contains [`small_map::SmallVecMap`], a [`map::VecMap`] that stores its first entries inline
+#[cfg(feature = "smallvec")]
+pub mod small_map;
+///Contains [`sorted_map::SortedVecMap`], a map for [`Ord`] keys that uses binary search instead of linear search
+pub mod sorted_map;
+///Contains [`static_map::StaticVecMap`], a read-only map over a `&'static` slice, usable in `static` items
+pub mod static_map;
+///Contains [`storage::Storage`], the trait abstracting over the backing storage of [`map::VecMap`] and [`set::VecSet`]
+pub mod storage;
@@ -0,0 +1,184 @@
+use alloc::vec::Vec;
+
+use crate::{
+    equivalent::Equivalent,
+    map::{Iter, Keys, Values, VecMap},
+};
+
+#[cfg(test)]
+mod tests;
+
+///A least recently used cache with a fixed capacity, built on [`VecMap`].
+///
+///The order of the entries encodes their recency: the most recently used entry is first and the least recently used
+///one is last. Since hot keys sit at the front, the linear search finds them after few comparisons, which makes this a
+///good fit for small caches where hashing would cost more than the scan.
+#[derive(Clone)]
+pub struct VecLruCache<K, V> {
+    map: VecMap<K, V>,
+    capacity: usize,
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug> core::fmt::Debug for VecLruCache<K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("VecLruCache")
+            .field("entries", &self.map)
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl<K: Eq, V> VecLruCache<K, V> {
+    ///Creates an empty cache holding at most `capacity` entries, a cache with a capacity of 0 never holds anything
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            map: VecMap::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    ///Returns the maximum number of entries in the cache
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    //Moves the entry at `index` to the front, marking it as the most recently used
+    fn refresh(&mut self, index: usize) {
+        self.map.move_index(index, 0);
+    }
+
+    ///Returns the value of the key and marks it as the most recently used entry
+    pub fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let index = self.map.get_index_of(k)?;
+        self.refresh(index);
+        self.map.first().map(|(_, v)| v)
+    }
+
+    ///Returns the value of the key and marks it as the most recently used entry
+    pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let index = self.map.get_index_of(k)?;
+        self.refresh(index);
+        self.map.get_index_mut(0).map(|(_, v)| v)
+    }
+
+    ///Returns the value of the key without changing its recency
+    pub fn peek<Q>(&self, k: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.get(k)
+    }
+
+    ///Returns the value of the key without changing its recency
+    pub fn peek_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.get_mut(k)
+    }
+
+    ///Returns the least recently used entry, the next one to be evicted, without changing its recency
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.map.last()
+    }
+
+    ///Returns `true` if the key is in the cache, without changing its recency
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.contains_key(k)
+    }
+
+    ///Inserts the pair as the most recently used entry, and returns the entry it pushed out.
+    ///
+    ///If the key was already cached, its previous pair is returned. Otherwise, if the cache was full, the least
+    ///recently used entry is evicted and returned. With a capacity of 0 the given pair is returned right away
+    pub fn put(&mut self, k: K, v: V) -> Option<(K, V)> {
+        if self.capacity == 0 {
+            return Some((k, v));
+        }
+        if let Some(index) = self.map.get_index_of(&k) {
+            self.refresh(index);
+            let (old_k, old_v) = &mut self.map.vec[0];
+            return Some((core::mem::replace(old_k, k), core::mem::replace(old_v, v)));
+        }
+        let evicted = if self.len() >= self.capacity {
+            self.map.pop()
+        } else {
+            None
+        };
+        //The key is known to be missing, so it's pushed without a lookup and moved to the front
+        self.map.vec.push((k, v));
+        self.refresh(self.len() - 1);
+        evicted
+    }
+
+    ///Removes the key from the cache and returns its value
+    pub fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.remove(k)
+    }
+
+    ///Removes and returns the least recently used entry
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        self.map.pop()
+    }
+
+    ///Changes the capacity of the cache, evicting the least recently used entries that no longer fit.
+    ///
+    ///The evicted entries are returned from the least to the most recently used
+    pub fn resize(&mut self, capacity: usize) -> Vec<(K, V)> {
+        self.capacity = capacity;
+        let mut evicted = Vec::new();
+        while self.len() > capacity {
+            evicted.extend(self.map.pop());
+        }
+        evicted
+    }
+
+    ///An iterator over the entries from the most to the least recently used, without changing their recency
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.map.iter()
+    }
+
+    ///An iterator over the keys from the most to the least recently used, without changing their recency
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.map.keys()
+    }
+
+    ///An iterator over the values from the most to the least recently used, without changing their recency
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.map.values()
+    }
+}
+
+impl<'a, K: Eq, V> IntoIterator for &'a VecLruCache<K, V> {
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,96 @@
+use std::{string::String, vec::Vec};
+
+use crate::lru_cache::*;
+
+fn keys<V>(cache: &VecLruCache<i32, V>) -> Vec<i32> {
+    cache.keys().copied().collect()
+}
+
+#[test]
+fn test_put_evicts() {
+    let mut cache = VecLruCache::new(3);
+    assert_eq!(cache.put(1, "a"), None);
+    assert_eq!(cache.put(2, "b"), None);
+    assert_eq!(cache.put(3, "c"), None);
+    assert_eq!(keys(&cache), vec![3, 2, 1]);
+
+    assert_eq!(cache.put(4, "d"), Some((1, "a")));
+    assert_eq!(keys(&cache), vec![4, 3, 2]);
+    assert_eq!(cache.len(), 3);
+
+    // Overwriting returns the old pair and refreshes the key
+    assert_eq!(cache.put(2, "B"), Some((2, "b")));
+    assert_eq!(keys(&cache), vec![2, 4, 3]);
+    assert_eq!(cache.peek_lru(), Some((&3, &"c")));
+}
+
+#[test]
+fn test_get_refreshes() {
+    let mut cache = VecLruCache::new(3);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    cache.put(3, 30);
+
+    assert_eq!(cache.get(&1), Some(&10));
+    assert_eq!(cache.put(4, 40), Some((2, 20)));
+
+    *cache.get_mut(&3).unwrap() += 1;
+    assert_eq!(keys(&cache), vec![3, 4, 1]);
+    assert_eq!(cache.get(&5), None);
+    assert_eq!(keys(&cache), vec![3, 4, 1]);
+}
+
+#[test]
+fn test_peek() {
+    let mut cache = VecLruCache::new(2);
+    cache.put(String::from("a"), 1);
+    cache.put(String::from("b"), 2);
+
+    assert_eq!(cache.peek("a"), Some(&1));
+    *cache.peek_mut("a").unwrap() += 1;
+    assert!(cache.contains_key("a"));
+    assert_eq!(
+        cache
+            .iter()
+            .map(|(k, v)| (k.as_str(), *v))
+            .collect::<Vec<_>>(),
+        vec![("b", 2), ("a", 2)]
+    );
+    assert_eq!(
+        cache.put(String::from("c"), 3),
+        Some((String::from("a"), 2))
+    );
+}
+
+#[test]
+fn test_pop_and_resize() {
+    let mut cache = VecLruCache::new(4);
+    for i in 0..4 {
+        cache.put(i, i);
+    }
+    assert_eq!(cache.pop(&2), Some(2));
+    assert_eq!(cache.pop(&2), None);
+    assert_eq!(cache.pop_lru(), Some((0, 0)));
+    assert_eq!(keys(&cache), vec![3, 1]);
+
+    cache.put(4, 4);
+    assert_eq!(cache.resize(1), vec![(1, 1), (3, 3)]);
+    assert_eq!(cache.capacity(), 1);
+    assert_eq!(keys(&cache), vec![4]);
+
+    assert_eq!(cache.resize(3), Vec::new());
+    cache.put(5, 5);
+    cache.put(6, 6);
+    assert_eq!(cache.values().copied().collect::<Vec<_>>(), vec![6, 5, 4]);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_zero_capacity() {
+    let mut cache = VecLruCache::new(0);
+    assert_eq!(cache.put(1, 1), Some((1, 1)));
+    assert!(cache.is_empty());
+    assert_eq!(cache.get(&1), None);
+}
@@ -1,40 +1,114 @@
-use std::{borrow::Borrow, collections::HashMap, ops::Index};
-
+use alloc::{
+    collections::{BTreeMap, TryReserveError},
+    vec::Vec,
+};
+use core::{
+    borrow::Borrow,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::Index,
+};
+#[cfg(feature = "std")]
+use std::{collections::HashMap, hash::BuildHasher};
+
+use crate::{
+    equivalent::Equivalent,
+    hash::hash_unordered,
+    storage::{CapacityError, Extractor, Storage},
+};
+
+mod raw_entry;
 #[cfg(test)]
+#[allow(
+    clippy::missing_const_for_thread_local,
+    clippy::nonminimal_bool,
+    clippy::assign_op_pattern,
+    clippy::no_effect
+)]
 mod tests;
 
+pub use raw_entry::{
+    RawEntryBuilder, RawEntryBuilderMut, RawEntryMut, RawOccupiedEntryMut, RawVacantEntryMut,
+};
+
 ///A drop in replacement for [`std::collections::HashMap`] for extensive documentation and examples, see the original data
 ///structure.
-#[derive(Default, Clone, Eq)]
-pub struct VecMap<K, V> {
-    vec: Vec<(K, V)>,
+///
+///Unlike [`std::collections::HashMap`], `VecMap` has a defined iteration order: entries are kept in the order
+///their keys were first inserted. Overwriting the value of an existing key keeps the entry in place, and removing an
+///entry shifts the following entries down by one, preserving their relative order.
+///
+///The entries are stored in a [`Vec`] by default, any other [`Storage`] can be used instead, like the `SmallVec` of
+///`SmallVecMap` with the `smallvec` feature.
+pub struct VecMap<K, V, S = Vec<(K, V)>> {
+    pub(crate) vec: S,
+    pub(crate) _marker: PhantomData<(K, V)>,
 }
 
-impl<K: std::fmt::Debug, V: std::fmt::Debug> std::fmt::Debug for VecMap<K, V> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<K, V, S: Default> Default for VecMap<K, V, S> {
+    fn default() -> Self {
+        Self {
+            vec: S::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V, S: Clone> Clone for VecMap<K, V, S> {
+    fn clone(&self) -> Self {
+        Self {
+            vec: self.vec.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug, S: Storage<(K, V)>> core::fmt::Debug
+    for VecMap<K, V, S>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         // f.debug_struct("VecMap").field("vec", &self.vec).finish()
         f.debug_map()
-            .entries(self.vec.iter().map(|(k, v)| (k, v)))
+            .entries(self.vec.as_slice().iter().map(|(k, v)| (k, v)))
             .finish()
     }
 }
 
-impl<K: PartialEq + Eq, V: PartialEq> PartialEq for VecMap<K, V> {
+///Two maps are equal if they contain the same entries, regardless of their order
+impl<K: PartialEq + Eq, V: PartialEq, S: Storage<(K, V)>> PartialEq for VecMap<K, V, S> {
     fn eq(&self, other: &Self) -> bool {
-        self.iter()
-            .map(|i| other.get(i.0).map(|j| j == i.1).unwrap_or_default())
-            .fold(true, |a, i| i && a)
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|i| other.get(i.0).is_some_and(|j| j == i.1))
+    }
+}
+
+impl<K: Eq, V: Eq, S: Storage<(K, V)>> Eq for VecMap<K, V, S> {}
+
+///The hash doesn't depend on the order of the entries, so that it's consistent with [`PartialEq`].
+///
+///To make it order independent, every entry is first hashed on its own with a fixed, unseeded hasher, and only the
+///combined result is fed into `state`. Seeding `state`, as `RandomState` does, therefore doesn't protect against
+///HashDoS: inputs whose entries collide under the fixed hasher collide under every seed. Don't use maps built from
+///untrusted input as keys of a hash based collection.
+impl<K: Hash, V: Hash, S: Storage<(K, V)>> Hash for VecMap<K, V, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_unordered(self.vec.as_slice().iter(), state);
     }
 }
 
-pub struct VaccantEntrty<'a, K: std::cmp::Eq, V> {
+pub struct VaccantEntrty<'a, K: core::cmp::Eq, V, S = Vec<(K, V)>> {
     key: K,
-    table: &'a mut Vec<(K, V)>,
+    table: &'a mut S,
+    _marker: PhantomData<&'a mut (K, V)>,
 }
 
-impl<'a, K, V> VaccantEntrty<'a, K, V>
+impl<'a, K, V, S> VaccantEntrty<'a, K, V, S>
 where
-    K: std::cmp::Eq,
+    K: core::cmp::Eq,
+    S: Storage<(K, V)>,
 {
     pub const fn key(&self) -> &K {
         &self.key
@@ -44,72 +118,115 @@ where
         self.key
     }
 
+    ///Returns the index the entry will have once inserted
+    pub fn index(&self) -> usize {
+        self.table.as_slice().len()
+    }
+
     pub fn insert(self, value: V) -> &'a mut V {
         let key = self.key;
         self.table.push((key, value));
         //When we insert a new value it is always last in the vec so this SHOULD be fine
-        &mut self.table.last_mut().unwrap().1
+        &mut self.table.as_mut_slice().last_mut().unwrap().1
+    }
+
+    ///Inserts the value into the map, returning the entry back in a [`CapacityError`] if the storage is full
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, CapacityError<(K, V)>> {
+        self.table.try_push((self.key, value))?;
+        Ok(&mut self.table.as_mut_slice().last_mut().unwrap().1)
     }
 }
-impl<K: std::fmt::Debug + std::cmp::Eq, V> std::fmt::Debug for VaccantEntrty<'_, K, V> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<K: core::fmt::Debug + core::cmp::Eq, V, S: Storage<(K, V)>> core::fmt::Debug
+    for VaccantEntrty<'_, K, V, S>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VacantEntry").field(self.key()).finish()
     }
 }
 
 //Sigh, can use RustcOccupied entry, gotta make shit up myself
-pub struct OccupiedEntrty<'a, K: std::cmp::Eq, V> {
+pub struct OccupiedEntrty<'a, K: core::cmp::Eq, V, S = Vec<(K, V)>> {
     ///Index of the entry, only useful in here
     index: usize,
     // key: &'a K,
-    table: &'a mut Vec<(K, V)>,
+    table: &'a mut S,
+    _marker: PhantomData<&'a mut (K, V)>,
 }
 
-impl<'a, K, V> OccupiedEntrty<'a, K, V>
+impl<'a, K, V, S> OccupiedEntrty<'a, K, V, S>
 where
-    K: std::cmp::Eq,
+    K: core::cmp::Eq,
+    S: Storage<(K, V)>,
 {
     pub fn get(&self) -> &V {
-        &self.table.get(self.index).unwrap().1
+        &self.table.as_slice().get(self.index).unwrap().1
     }
 
     pub fn get_mut(&mut self) -> &mut V {
-        &mut self.table.get_mut(self.index).unwrap().1
+        &mut self.table.as_mut_slice().get_mut(self.index).unwrap().1
     }
 
+    ///Sets the value of the entry, keeping its position in the map, and returns the old value
     pub fn insert(&mut self, value: V) -> V {
-        let old = self.table.remove(self.index);
-        self.table.push((old.0, value));
-        old.1
+        core::mem::replace(self.get_mut(), value)
     }
 
     pub fn into_mut(self) -> &'a mut V {
-        &mut self.table.get_mut(self.index).unwrap().1
+        &mut self.table.as_mut_slice().get_mut(self.index).unwrap().1
     }
 
     pub fn key(&self) -> &K {
-        &self.table.get(self.index).unwrap().0
+        &self.table.as_slice().get(self.index).unwrap().0
+    }
+
+    ///Returns the index of the entry in the map
+    pub const fn index(&self) -> usize {
+        self.index
     }
+
+    ///Removes the entry from the map, shifting all following entries down by one
     pub fn remove(self) -> V {
-        self.table.swap_remove(self.index).1
+        self.shift_remove()
     }
 
+    ///Removes the entry from the map, shifting all following entries down by one
     pub fn remove_entry(self) -> (K, V) {
+        self.shift_remove_entry()
+    }
+
+    ///Removes the entry from the map by swapping it with the last entry, this does not preserve the order of the map
+    pub fn swap_remove(self) -> V {
+        self.swap_remove_entry().1
+    }
+
+    ///Removes the entry from the map by swapping it with the last entry, this does not preserve the order of the map
+    pub fn swap_remove_entry(self) -> (K, V) {
+        self.table.swap_remove(self.index)
+    }
+
+    ///Removes the entry from the map, shifting all following entries down by one
+    pub fn shift_remove(self) -> V {
+        self.shift_remove_entry().1
+    }
+
+    ///Removes the entry from the map, shifting all following entries down by one
+    pub fn shift_remove_entry(self) -> (K, V) {
         self.table.remove(self.index)
     }
 }
 
-pub enum Entry<'a, K, V>
+pub enum Entry<'a, K, V, S = Vec<(K, V)>>
 where
-    K: std::cmp::Eq,
+    K: core::cmp::Eq,
 {
-    Occupied(OccupiedEntrty<'a, K, V>),
-    Vacant(VaccantEntrty<'a, K, V>),
+    Occupied(OccupiedEntrty<'a, K, V, S>),
+    Vacant(VaccantEntrty<'a, K, V, S>),
 }
 
-impl<'a, K, V> Entry<'a, K, V>
+impl<'a, K, V, S> Entry<'a, K, V, S>
 where
-    K: std::cmp::Eq,
+    K: core::cmp::Eq,
+    S: Storage<(K, V)>,
 {
     pub fn or_insert(self, default: V) -> &'a mut V {
         match self {
@@ -118,6 +235,15 @@ where
         }
     }
 
+    ///Ensures a value is in the entry by inserting the default if empty, returning the rejected entry if the storage
+    ///is full
+    pub fn or_try_insert(self, default: V) -> Result<&'a mut V, CapacityError<(K, V)>> {
+        match self {
+            Entry::Occupied(e) => Ok(e.into_mut()),
+            Entry::Vacant(e) => e.try_insert(default),
+        }
+    }
+
     pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
         match self {
             Entry::Occupied(e) => e.into_mut(),
@@ -139,6 +265,14 @@ where
             Entry::Vacant(e) => e.key(),
         }
     }
+
+    ///Returns the index of the entry, or the index it will have once inserted if it's vacant
+    pub fn index(&self) -> usize {
+        match self {
+            Entry::Occupied(e) => e.index(),
+            Entry::Vacant(e) => e.index(),
+        }
+    }
     #[must_use]
     pub fn and_modify<F>(self, f: F) -> Self
     where
@@ -154,23 +288,156 @@ where
         }
     }
 }
-impl<'a, K, V> Entry<'a, K, V>
+impl<'a, K, V, S> Entry<'a, K, V, S>
+where
+    K: core::cmp::Eq,
+    V: Default,
+    S: Storage<(K, V)>,
+{
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert(V::default())
+    }
+}
+
+///A view into a single entry in a map, obtained from a borrowed key, created by [`VecMap::entry_ref`]
+pub enum EntryRef<'a, 'b, K, Q, V, S = Vec<(K, V)>>
+where
+    K: core::cmp::Eq,
+    Q: ?Sized,
+{
+    Occupied(OccupiedEntrty<'a, K, V, S>),
+    Vacant(VacantEntryRef<'a, 'b, K, Q, V, S>),
+}
+
+impl<'a, 'b, K, Q, V, S> EntryRef<'a, 'b, K, Q, V, S>
+where
+    K: core::cmp::Eq + From<&'b Q>,
+    Q: ?Sized,
+    S: Storage<(K, V)>,
+{
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            EntryRef::Occupied(e) => e.into_mut(),
+            EntryRef::Vacant(e) => e.insert(default),
+        }
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            EntryRef::Occupied(e) => e.into_mut(),
+            EntryRef::Vacant(e) => e.insert(default()),
+        }
+    }
+
+    pub fn or_insert_with_key<F: FnOnce(&Q) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            EntryRef::Occupied(e) => e.into_mut(),
+            EntryRef::Vacant(e) => {
+                let value = default(e.key);
+                e.insert(value)
+            }
+        }
+    }
+
+    pub fn key(&self) -> &Q
+    where
+        K: Borrow<Q>,
+    {
+        match self {
+            EntryRef::Occupied(e) => e.key().borrow(),
+            EntryRef::Vacant(e) => e.key(),
+        }
+    }
+
+    ///Returns the index of the entry, or the index it will have once inserted if it's vacant
+    pub fn index(&self) -> usize {
+        match self {
+            EntryRef::Occupied(e) => e.index(),
+            EntryRef::Vacant(e) => e.index(),
+        }
+    }
+
+    #[must_use]
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            EntryRef::Occupied(mut e) => {
+                f(e.get_mut());
+                EntryRef::Occupied(e)
+            }
+            EntryRef::Vacant(_) => self,
+        }
+    }
+}
+
+impl<'a, 'b, K, Q, V, S> EntryRef<'a, 'b, K, Q, V, S>
 where
-    K: std::cmp::Eq,
+    K: core::cmp::Eq + From<&'b Q>,
+    Q: ?Sized,
     V: Default,
+    S: Storage<(K, V)>,
 {
     pub fn or_default(self) -> &'a mut V {
         self.or_insert(V::default())
     }
 }
 
-pub struct IntoIter<K, V> {
-    iter: std::vec::IntoIter<(K, V)>,
+///A view into a vacant entry in a map, holding a borrowed key that is only converted into an owned key on insertion
+pub struct VacantEntryRef<'a, 'b, K, Q, V, S = Vec<(K, V)>>
+where
+    K: core::cmp::Eq,
+    Q: ?Sized,
+{
+    key: &'b Q,
+    table: &'a mut S,
+    _marker: PhantomData<&'a mut (K, V)>,
 }
 
-impl<K, V> Iterator for IntoIter<K, V>
+impl<'a, 'b, K, Q, V, S> VacantEntryRef<'a, 'b, K, Q, V, S>
+where
+    K: core::cmp::Eq,
+    Q: ?Sized,
+    S: Storage<(K, V)>,
+{
+    pub const fn key(&self) -> &'b Q {
+        self.key
+    }
+
+    ///Returns the index the entry will have once inserted
+    pub fn index(&self) -> usize {
+        self.table.as_slice().len()
+    }
+
+    ///Converts the borrowed key into an owned one and inserts it with the given value
+    pub fn insert(self, value: V) -> &'a mut V
+    where
+        K: From<&'b Q>,
+    {
+        self.table.push((K::from(self.key), value));
+        //When we insert a new value it is always last in the vec so this SHOULD be fine
+        &mut self.table.as_mut_slice().last_mut().unwrap().1
+    }
+}
+
+impl<K: core::cmp::Eq, Q: core::fmt::Debug + ?Sized, V, S: Storage<(K, V)>> core::fmt::Debug
+    for VacantEntryRef<'_, '_, K, Q, V, S>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("VacantEntryRef").field(&self.key()).finish()
+    }
+}
+
+pub struct IntoIter<K, V, S: Storage<(K, V)> = Vec<(K, V)>> {
+    pub(crate) iter: S::IntoIter,
+    pub(crate) _marker: PhantomData<(K, V)>,
+}
+
+impl<K, V, S> Iterator for IntoIter<K, V, S>
 where
     K: Eq,
+    S: Storage<(K, V)>,
 {
     type Item = (K, V);
 
@@ -179,33 +446,36 @@ where
     }
 }
 
-impl<K, V> ExactSizeIterator for IntoIter<K, V>
+impl<K, V, S> ExactSizeIterator for IntoIter<K, V, S>
 where
     K: Eq,
+    S: Storage<(K, V)>,
 {
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<K, V> IntoIterator for VecMap<K, V>
+impl<K, V, S> IntoIterator for VecMap<K, V, S>
 where
     K: Eq,
+    S: Storage<(K, V)>,
 {
     type Item = (K, V);
 
-    type IntoIter = IntoIter<K, V>;
+    type IntoIter = IntoIter<K, V, S>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter {
             iter: self.vec.into_iter(),
+            _marker: PhantomData,
         }
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct Keys<'a, K, V> {
-    inner: core::slice::Iter<'a, (K, V)>,
+    pub(crate) inner: core::slice::Iter<'a, (K, V)>,
 }
 
 impl<'a, K, V> Iterator for Keys<'a, K, V> {
@@ -226,18 +496,19 @@ impl<K, V> ExactSizeIterator for Keys<'_, K, V> {
 }
 
 #[derive(Clone, Debug)]
-pub struct IntoKeys<K, V> {
-    inner: std::vec::IntoIter<(K, V)>,
+pub struct IntoKeys<K, V, S: Storage<(K, V)> = Vec<(K, V)>> {
+    pub(crate) inner: S::IntoIter,
+    pub(crate) _marker: PhantomData<(K, V)>,
 }
 
-impl<K, V> Iterator for IntoKeys<K, V> {
+impl<K, V, S: Storage<(K, V)>> Iterator for IntoKeys<K, V, S> {
     type Item = K;
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|i| i.0)
     }
 }
 
-impl<K, V> ExactSizeIterator for IntoKeys<K, V> {
+impl<K, V, S: Storage<(K, V)>> ExactSizeIterator for IntoKeys<K, V, S> {
     fn len(&self) -> usize {
         self.inner.len()
     }
@@ -245,7 +516,7 @@ impl<K, V> ExactSizeIterator for IntoKeys<K, V> {
 
 #[derive(Clone, Debug)]
 pub struct Values<'a, K, V> {
-    inner: core::slice::Iter<'a, (K, V)>,
+    pub(crate) inner: core::slice::Iter<'a, (K, V)>,
 }
 
 impl<'a, K, V> Iterator for Values<'a, K, V> {
@@ -267,7 +538,7 @@ impl<K, V> ExactSizeIterator for Values<'_, K, V> {
 
 #[derive(Debug)]
 pub struct ValuesMut<'a, K, V> {
-    inner: core::slice::IterMut<'a, (K, V)>,
+    pub(crate) inner: core::slice::IterMut<'a, (K, V)>,
 }
 
 impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
@@ -288,18 +559,19 @@ impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> {
 }
 
 #[derive(Clone, Debug)]
-pub struct IntoValues<K, V> {
-    inner: std::vec::IntoIter<(K, V)>,
+pub struct IntoValues<K, V, S: Storage<(K, V)> = Vec<(K, V)>> {
+    pub(crate) inner: S::IntoIter,
+    pub(crate) _marker: PhantomData<(K, V)>,
 }
 
-impl<K, V> Iterator for IntoValues<K, V> {
+impl<K, V, S: Storage<(K, V)>> Iterator for IntoValues<K, V, S> {
     type Item = V;
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|i| i.1)
     }
 }
 
-impl<K, V> ExactSizeIterator for IntoValues<K, V> {
+impl<K, V, S: Storage<(K, V)>> ExactSizeIterator for IntoValues<K, V, S> {
     fn len(&self) -> usize {
         self.inner.len()
     }
@@ -307,7 +579,7 @@ impl<K, V> ExactSizeIterator for IntoValues<K, V> {
 
 #[derive(Clone, Debug)]
 pub struct Iter<'a, K, V> {
-    inner: core::slice::Iter<'a, (K, V)>,
+    pub(crate) inner: core::slice::Iter<'a, (K, V)>,
 }
 
 impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {
@@ -330,7 +602,7 @@ impl<'a, K, V> Iterator for Iter<'a, K, V> {
 
 #[derive(Debug)]
 pub struct IterMut<'a, K, V> {
-    inner: core::slice::IterMut<'a, (K, V)>,
+    pub(crate) inner: core::slice::IterMut<'a, (K, V)>,
 }
 
 impl<'a, K, V> Iterator for IterMut<'a, K, V> {
@@ -352,11 +624,11 @@ impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {
 }
 
 #[derive(Debug)]
-pub struct Drain<'a, K, V> {
-    inner: std::vec::Drain<'a, (K, V)>,
+pub struct Drain<'a, K, V, S: Storage<(K, V)> + 'a = Vec<(K, V)>> {
+    pub(crate) inner: S::Drain<'a>,
 }
 
-impl<K, V> Iterator for Drain<'_, K, V> {
+impl<K, V, S: Storage<(K, V)>> Iterator for Drain<'_, K, V, S> {
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -364,25 +636,146 @@ impl<K, V> Iterator for Drain<'_, K, V> {
     }
 }
 
-impl<K, V> ExactSizeIterator for Drain<'_, K, V> {
+impl<K, V, S: Storage<(K, V)>> ExactSizeIterator for Drain<'_, K, V, S> {
     fn len(&self) -> usize {
         self.inner.len()
     }
 }
 
+///A lazy iterator removing the entries matching a predicate, created by [`VecMap::extract_if`]
+pub struct ExtractIf<'a, K, V, F, S = Vec<(K, V)>>
+where
+    F: FnMut(&K, &mut V) -> bool,
+    S: Storage<(K, V)>,
+{
+    pub(crate) inner: Extractor<'a, (K, V), S>,
+    pub(crate) pred: F,
+}
+
+impl<K, V, F, S> Iterator for ExtractIf<'_, K, V, F, S>
+where
+    F: FnMut(&K, &mut V) -> bool,
+    S: Storage<(K, V)>,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pred = &mut self.pred;
+        self.inner.next(|(k, v)| pred(k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.inner.remaining()))
+    }
+}
+
+impl<K, V, F, S> FusedIterator for ExtractIf<'_, K, V, F, S>
+where
+    F: FnMut(&K, &mut V) -> bool,
+    S: Storage<(K, V)>,
+{
+}
+
+impl<K, V, F, S> core::fmt::Debug for ExtractIf<'_, K, V, F, S>
+where
+    F: FnMut(&K, &mut V) -> bool,
+    S: Storage<(K, V)>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ExtractIf").finish_non_exhaustive()
+    }
+}
+
 impl<K, V> VecMap<K, V>
 where
     K: Eq,
 {
     ///Creates an empty `VecMap`
     pub const fn new() -> Self {
-        Self { vec: Vec::new() }
+        Self {
+            vec: Vec::new(),
+            _marker: PhantomData,
+        }
     }
 
     //Creates an empty `VecMap` with at least the specified capacity
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             vec: Vec::with_capacity(capacity),
+            _marker: PhantomData,
+        }
+    }
+
+    ///Creates an empty `VecMap` with at least the specified capacity, returning an error instead of aborting if the
+    ///allocation fails
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        let mut o = Self::new();
+        o.try_reserve(capacity)?;
+        Ok(o)
+    }
+
+    ///Tries to reserve capacity for at least `additional` more elements, returning an error instead of aborting if
+    ///the allocation fails
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.vec.try_reserve(additional)
+    }
+
+    ///Same as [`VecMap::insert`], but returns an error instead of aborting if growing the map fails. The map is
+    ///left unchanged in that case
+    pub fn try_insert_alloc(&mut self, k: K, v: V) -> Result<Option<V>, TryReserveError> {
+        if let Some(old) = self.get_mut(&k) {
+            return Ok(Some(core::mem::replace(old, v)));
+        }
+        self.vec.try_reserve(1)?;
+        self.vec.push((k, v));
+
+        Ok(None)
+    }
+
+    ///Fallible version of [`Extend::extend`], inserts all pairs from the iterator, stopping at the first allocation
+    ///failure. Pairs inserted before the failure are kept in the map
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), TryReserveError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let iter = iter.into_iter();
+        self.vec.try_reserve(iter.size_hint().0)?;
+        for (k, v) in iter {
+            self.try_insert_alloc(k, v)?;
+        }
+        Ok(())
+    }
+
+    ///Fallible version of [`FromIterator::from_iter`], returns an error instead of aborting if an allocation fails
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, TryReserveError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut o = Self::new();
+        o.try_extend(iter)?;
+        Ok(o)
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit();
+    }
+
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.vec.shrink_to(min_capacity);
+    }
+}
+
+impl<K, V, S> VecMap<K, V, S>
+where
+    K: Eq,
+    S: Storage<(K, V)>,
+{
+    ///Creates an empty `VecMap` backed by the given storage, any elements already in it are removed
+    pub fn with_storage(mut storage: S) -> Self {
+        storage.clear();
+        Self {
+            vec: storage,
+            _marker: PhantomData,
         }
     }
 
@@ -396,57 +789,105 @@ where
     }
 
     pub fn len(&self) -> usize {
-        self.vec.len()
+        self.vec.as_slice().len()
     }
 
+    ///Inserts a key-value pair into the map.
+    ///
+    ///If the map already had this key present, the value is updated in place, keeping the position of the entry, and
+    ///the old value is returned. The key is not updated. Otherwise the pair is appended to the end of the map.
     pub fn insert(&mut self, k: K, v: V) -> Option<V> {
-        let old = self.remove(&k);
+        if let Some(old) = self.get_mut(&k) {
+            return Some(core::mem::replace(old, v));
+        }
         self.vec.push((k, v));
 
-        old
+        None
+    }
+
+    ///Inserts a key-value pair into the map like [`VecMap::insert`]. Overwriting an existing key always succeeds, if
+    ///the key is new and the storage is full, the pair is returned in a [`CapacityError`].
+    ///
+    ///Only storages with a fixed capacity, like `ArrayVec`, can fail, [`VecMap::insert`] panics instead
+    pub fn try_insert(&mut self, k: K, v: V) -> Result<Option<V>, CapacityError<(K, V)>> {
+        if let Some(old) = self.get_mut(&k) {
+            return Ok(Some(core::mem::replace(old, v)));
+        }
+        self.vec.try_push((k, v))?;
+
+        Ok(None)
     }
 
+    ///Removes a key from the map, returning its value. All following entries are shifted down by one, so the
+    ///order of the remaining entries is preserved, see [`VecMap::shift_remove`]
     pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
     where
-        K: Borrow<Q> + PartialEq<Q>,
-        Q: Eq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
-        let mut ind = None;
-        for (index, i) in self.vec.iter().enumerate() {
-            if i.0 == *k {
-                ind = Some(index);
-            }
-        }
-        if let Some(ind) = ind {
-            return Some(self.vec.remove(ind).1);
-        }
-        None
+        self.shift_remove(k)
     }
 
+    ///Removes a key from the map, returning the stored key and value. All following entries are shifted down by
+    ///one, so the order of the remaining entries is preserved, see [`VecMap::shift_remove_entry`]
     pub fn remove_entry<Q>(&mut self, k: &Q) -> Option<(K, V)>
     where
-        K: Borrow<Q> + PartialEq<Q>,
-        Q: Eq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
-        let mut ind = None;
-        for (index, i) in self.vec.iter().enumerate() {
-            if i.0 == *k {
-                ind = Some(index);
-            }
-        }
-        if let Some(ind) = ind {
-            return Some(self.vec.remove(ind));
-        }
-        None
+        self.shift_remove_entry(k)
+    }
+
+    ///Removes a key from the map, returning its value. The entry is replaced by the last entry of the map, this
+    ///does not preserve the order of the map, but is O(1) once the key is found
+    pub fn swap_remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.swap_remove_entry(k).map(|i| i.1)
+    }
+
+    ///Removes a key from the map, returning the stored key and value. The entry is replaced by the last entry of
+    ///the map, this does not preserve the order of the map, but is O(1) once the key is found
+    pub fn swap_remove_entry<Q>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let ind = self
+            .vec
+            .as_slice()
+            .iter()
+            .position(|i| k.equivalent(&i.0))?;
+        Some(self.vec.swap_remove(ind))
+    }
+
+    ///Removes a key from the map, returning its value. All following entries are shifted down by one, so the
+    ///order of the remaining entries is preserved
+    pub fn shift_remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.shift_remove_entry(k).map(|i| i.1)
+    }
+
+    ///Removes a key from the map, returning the stored key and value. All following entries are shifted down by
+    ///one, so the order of the remaining entries is preserved
+    pub fn shift_remove_entry<Q>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let ind = self
+            .vec
+            .as_slice()
+            .iter()
+            .position(|i| k.equivalent(&i.0))?;
+        Some(self.vec.remove(ind))
     }
 
     pub fn get<Q>(&self, k: &Q) -> Option<&V>
     where
-        K: Borrow<Q>,
-        Q: Eq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
-        for (key, v) in &self.vec {
-            if k == key.borrow() {
+        for (key, v) in self.vec.as_slice() {
+            if k.equivalent(key) {
                 return Some(v);
             }
         }
@@ -455,67 +896,136 @@ where
 
     pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
     where
-        K: Borrow<Q>,
-        Q: Eq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
-        let mut ind = None;
-        for (index, (key, _)) in self.vec.iter().enumerate() {
-            if k == key.borrow() {
-                ind = Some(index);
-            }
+        let ind = self.get_index_of(k)?;
+        Some(&mut self.vec.as_mut_slice()[ind].1)
+    }
+
+    ///Returns mutable references to the values of `N` keys at once.
+    ///
+    ///Returns `None` if any of the keys is missing, or if any two keys refer to the same entry
+    pub fn get_many_mut<Q, const N: usize>(&mut self, ks: [&Q; N]) -> Option<[&mut V; N]>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let indices = self.get_many_indices(ks)?;
+        //Visit the indices in ascending order, so each entry can be split off the rest of the slice
+        let mut order: [usize; N] = core::array::from_fn(|i| i);
+        order.sort_unstable_by_key(|&i| indices[i]);
+        if order.windows(2).any(|w| indices[w[0]] == indices[w[1]]) {
+            return None;
+        }
+
+        let mut values: [Option<&mut V>; N] = core::array::from_fn(|_| None);
+        let mut rest = self.vec.as_mut_slice();
+        let mut offset = 0;
+        for i in order {
+            let (_, tail) = core::mem::take(&mut rest).split_at_mut(indices[i] - offset);
+            let ((_, v), tail) = tail.split_first_mut()?;
+            values[i] = Some(v);
+            rest = tail;
+            offset = indices[i] + 1;
         }
-        Some(&mut self.vec.get_mut(ind?)?.1)
+        Some(values.map(|v| v.unwrap()))
+    }
+
+    ///Returns mutable references to the values of `N` keys at once, without checking that the keys are distinct.
+    ///
+    ///Returns `None` if any of the keys is missing
+    ///
+    ///# Safety
+    ///
+    ///Calling this method with keys that refer to the same entry is undefined behavior, even if the returned
+    ///references are never used
+    pub unsafe fn get_many_unchecked_mut<Q, const N: usize>(
+        &mut self,
+        ks: [&Q; N],
+    ) -> Option<[&mut V; N]>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let indices = self.get_many_indices(ks)?;
+        let entries = self.vec.as_mut_slice().as_mut_ptr();
+        //SAFETY: all indices are in bounds, the caller guarantees they are distinct
+        Some(indices.map(|i| unsafe { &mut (*entries.add(i)).1 }))
+    }
+
+    fn get_many_indices<Q, const N: usize>(&self, ks: [&Q; N]) -> Option<[usize; N]>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let mut indices = [0; N];
+        for (i, k) in ks.into_iter().enumerate() {
+            indices[i] = self.get_index_of(k)?;
+        }
+        Some(indices)
     }
 
     pub fn contains_key<Q>(&self, k: &Q) -> bool
     where
-        K: Borrow<Q> + PartialEq<Q>,
-        Q: Eq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
-        for (key, _) in &self.vec {
-            if key == k {
+        for (key, _) in self.vec.as_slice() {
+            if k.equivalent(key) {
                 return true;
             }
         }
         false
     }
 
-    pub fn shrink_to_fit(&mut self) {
-        self.vec.shrink_to_fit();
-    }
-
-    pub fn shrink_to(&mut self, min_capacity: usize) {
-        self.vec.shrink_to(min_capacity);
-    }
-
-    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
-        match {
-            let mut val = None;
-            for (index, (map_key, _)) in self.vec.iter().enumerate() {
-                if &key == map_key {
-                    val = Some(index);
-                    break;
-                }
-            }
-            val
-        } {
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        match self.get_index_of(&key) {
             Some(e) => Entry::Occupied(OccupiedEntrty {
                 index: e,
                 table: &mut self.vec,
+                _marker: PhantomData,
             }),
             None => Entry::Vacant(VaccantEntrty {
                 key,
                 table: &mut self.vec,
+                _marker: PhantomData,
+            }),
+        }
+    }
+    ///Gets the entry for the given borrowed key, the key is only converted into an owned `K` if a vacant entry
+    ///is inserted into
+    pub fn entry_ref<'b, Q>(&mut self, key: &'b Q) -> EntryRef<'_, 'b, K, Q, V, S>
+    where
+        K: From<&'b Q>,
+        Q: Equivalent<K> + ?Sized,
+    {
+        match self.get_index_of(key) {
+            Some(e) => EntryRef::Occupied(OccupiedEntrty {
+                index: e,
+                table: &mut self.vec,
+                _marker: PhantomData,
+            }),
+            None => EntryRef::Vacant(VacantEntryRef {
+                key,
+                table: &mut self.vec,
+                _marker: PhantomData,
             }),
         }
     }
+
+    ///Creates a raw immutable entry builder, allowing to look up entries by a predicate instead of an owned key
+    pub fn raw_entry(&self) -> RawEntryBuilder<'_, K, V, S> {
+        RawEntryBuilder { map: self }
+    }
+
+    ///Creates a raw entry builder, allowing to look up entries by a predicate or a borrowed key, and only construct
+    ///the owned key if the entry is vacant
+    pub fn raw_entry_mut(&mut self) -> RawEntryBuilderMut<'_, K, V, S> {
+        RawEntryBuilderMut { map: self }
+    }
+
     pub fn get_key_value<Q>(&self, k: &Q) -> Option<(&K, &V)>
     where
-        K: Borrow<Q> + PartialEq<Q>,
-        Q: Eq + ?Sized,
+        Q: Equivalent<K> + ?Sized,
     {
-        for i in &self.vec {
-            if &i.0 == k {
+        for i in self.vec.as_slice() {
+            if k.equivalent(&i.0) {
                 return Some((&i.0, &i.1));
             }
         }
@@ -524,53 +1034,69 @@ where
 
     pub fn keys(&self) -> Keys<'_, K, V> {
         Keys {
-            inner: self.vec.iter(),
+            inner: self.vec.as_slice().iter(),
         }
     }
 
-    pub fn into_keys(self) -> IntoKeys<K, V> {
+    pub fn into_keys(self) -> IntoKeys<K, V, S> {
         IntoKeys {
             inner: self.vec.into_iter(),
+            _marker: PhantomData,
         }
     }
 
     pub fn values(&self) -> Values<'_, K, V> {
         Values {
-            inner: self.vec.iter(),
+            inner: self.vec.as_slice().iter(),
         }
     }
 
     pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
         ValuesMut {
-            inner: self.vec.iter_mut(),
+            inner: self.vec.as_mut_slice().iter_mut(),
         }
     }
 
-    pub fn into_values(self) -> IntoValues<K, V> {
+    pub fn into_values(self) -> IntoValues<K, V, S> {
         IntoValues {
             inner: self.vec.into_iter(),
+            _marker: PhantomData,
         }
     }
 
     pub fn iter(&self) -> Iter<'_, K, V> {
         Iter {
-            inner: self.vec.iter(),
+            inner: self.vec.as_slice().iter(),
         }
     }
 
     pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
         IterMut {
-            inner: self.vec.iter_mut(),
+            inner: self.vec.as_mut_slice().iter_mut(),
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.vec.is_empty()
+        self.vec.as_slice().is_empty()
     }
 
-    pub fn drain(&mut self) -> Drain<'_, K, V> {
+    pub fn drain(&mut self) -> Drain<'_, K, V, S> {
         Drain {
-            inner: self.vec.drain(..),
+            inner: self.vec.drain(),
+        }
+    }
+
+    ///Creates an iterator that removes and yields all entries for which `pred` returns `true`, in order.
+    ///
+    ///Entries are only visited as the iterator is advanced, if it's dropped before being exhausted, all entries that
+    ///weren't visited yet are kept in the map. The order of the remaining entries is preserved
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F, S>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        ExtractIf {
+            inner: Extractor::new(&mut self.vec),
+            pred,
         }
     }
 
@@ -586,18 +1112,94 @@ where
         self.vec.clear();
     }
 
-    pub fn hasher<S>(&self) -> &S
+    pub fn hasher<H>(&self) -> &H
     where
-        S: std::hash::BuildHasher,
+        H: core::hash::BuildHasher,
     {
         unimplemented!("Hasher is not implemented for VecMap");
     }
+
+    ///Returns the key-value pair at the given index, or `None` if the index is out of bounds
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.vec.as_slice().get(index).map(|(k, v)| (k, v))
+    }
+
+    ///Returns the key and a mutable reference to the value at the given index, or `None` if the index is out of
+    ///bounds
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.vec
+            .as_mut_slice()
+            .get_mut(index)
+            .map(|(k, v)| (&(*k), v))
+    }
+
+    ///Returns the index of the given key, if it's present in the map
+    pub fn get_index_of<Q>(&self, k: &Q) -> Option<usize>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.vec
+            .as_slice()
+            .iter()
+            .position(|(key, _)| k.equivalent(key))
+    }
+
+    ///Swaps the positions of two entries in the map
+    ///
+    ///Panics if either `a` or `b` are out of bounds
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        self.vec.as_mut_slice().swap(a, b);
+    }
+
+    ///Moves the entry at index `from` to index `to`, shifting all entries in between to fill the gap
+    ///
+    ///Panics if either `from` or `to` are out of bounds
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        if from < to {
+            self.vec.as_mut_slice()[from..=to].rotate_left(1);
+        } else {
+            self.vec.as_mut_slice()[to..=from].rotate_right(1);
+        }
+    }
+
+    ///Returns the first key-value pair in the map
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.vec.as_slice().first().map(|(k, v)| (k, v))
+    }
+
+    ///Returns the last key-value pair in the map
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.vec.as_slice().last().map(|(k, v)| (k, v))
+    }
+
+    ///Removes and returns the last key-value pair in the map
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.vec.pop()
+    }
+
+    ///Removes the key-value pair at the given index by swapping it with the last entry, this does not preserve the
+    ///order of the map, but is O(1)
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(self.vec.swap_remove(index))
+    }
+
+    ///Removes the key-value pair at the given index, shifting all following entries down by one
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(self.vec.remove(index))
+    }
 }
 
-impl<'a, K, V> Extend<(&'a K, &'a V)> for VecMap<K, V>
+impl<'a, K, V, S> Extend<(&'a K, &'a V)> for VecMap<K, V, S>
 where
     K: Eq + Copy,
     V: Copy,
+    S: Storage<(K, V)>,
 {
     fn extend<T: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: T) {
         for (k, v) in iter {
@@ -606,9 +1208,10 @@ where
     }
 }
 
-impl<K, V> Extend<(K, V)> for VecMap<K, V>
+impl<K, V, S> Extend<(K, V)> for VecMap<K, V, S>
 where
     K: Eq,
+    S: Storage<(K, V)>,
 {
     fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
         for (k, v) in iter {
@@ -630,12 +1233,79 @@ where
     }
 }
 
-impl<K, V> FromIterator<(K, V)> for VecMap<K, V>
+///Duplicate keys are handled like [`VecMap::insert`], the last value wins and takes the position of the first
+///occurrence of the key
+impl<K, V> From<Vec<(K, V)>> for VecMap<K, V>
+where
+    K: Eq,
+{
+    fn from(value: Vec<(K, V)>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<K, V, S> From<VecMap<K, V, S>> for Vec<(K, V)>
 where
     K: Eq,
+    S: Storage<(K, V)>,
+{
+    fn from(value: VecMap<K, V, S>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+///The entries are kept in the iteration order of the [`HashMap`]
+#[cfg(feature = "std")]
+impl<K, V, H> From<HashMap<K, V, H>> for VecMap<K, V> {
+    fn from(value: HashMap<K, V, H>) -> Self {
+        //The keys are already unique, so there's no need to look them up
+        Self {
+            vec: value.into_iter().collect(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S, H> From<VecMap<K, V, S>> for HashMap<K, V, H>
+where
+    K: Eq + Hash,
+    S: Storage<(K, V)>,
+    H: BuildHasher + Default,
+{
+    fn from(value: VecMap<K, V, S>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+///The entries are kept sorted by key
+impl<K, V> From<BTreeMap<K, V>> for VecMap<K, V> {
+    fn from(value: BTreeMap<K, V>) -> Self {
+        //The keys are already unique, so there's no need to look them up
+        Self {
+            vec: value.into_iter().collect(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, V, S> From<VecMap<K, V, S>> for BTreeMap<K, V>
+where
+    K: Ord,
+    S: Storage<(K, V)>,
+{
+    fn from(value: VecMap<K, V, S>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for VecMap<K, V, S>
+where
+    K: Eq,
+    S: Storage<(K, V)> + Default,
 {
     fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
-        let mut o = Self::new();
+        let mut o = Self::default();
         for (k, v) in iter {
             o.insert(k, v);
         }
@@ -643,10 +1313,11 @@ where
     }
 }
 
-impl<K, Q, V> Index<&Q> for VecMap<K, V>
+impl<K, Q, V, S> Index<&Q> for VecMap<K, V, S>
 where
-    K: Eq + Borrow<Q>,
-    Q: Eq + ?Sized,
+    K: Eq,
+    Q: Equivalent<K> + ?Sized,
+    S: Storage<(K, V)>,
 {
     type Output = V;
 
@@ -655,36 +1326,74 @@ where
     }
 }
 
-impl<'a, K, V> IntoIterator for &'a VecMap<K, V> {
+impl<'a, K, V, S: Storage<(K, V)>> IntoIterator for &'a VecMap<K, V, S> {
     type Item = (&'a K, &'a V);
 
     type IntoIter = Iter<'a, K, V>;
 
     fn into_iter(self) -> Self::IntoIter {
         Iter {
-            inner: self.vec.iter(),
+            inner: self.vec.as_slice().iter(),
         }
     }
 }
 
-impl<'a, K, V> IntoIterator for &'a mut VecMap<K, V> {
+impl<'a, K, V, S: Storage<(K, V)>> IntoIterator for &'a mut VecMap<K, V, S> {
     type Item = (&'a K, &'a mut V);
 
     type IntoIter = IterMut<'a, K, V>;
 
     fn into_iter(self) -> Self::IntoIter {
         IterMut {
-            inner: self.vec.iter_mut(),
+            inner: self.vec.as_mut_slice().iter_mut(),
         }
     }
 }
 
-impl<K, V> PartialEq<HashMap<K, V>> for VecMap<K, V>
+#[cfg(feature = "std")]
+impl<K, V, S, H> PartialEq<HashMap<K, V, H>> for VecMap<K, V, S>
 where
-    K: Eq,
+    K: Eq + Hash,
+    V: PartialEq,
+    S: Storage<(K, V)>,
+    H: BuildHasher,
+{
+    fn eq(&self, other: &HashMap<K, V, H>) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S, H> PartialEq<VecMap<K, V, S>> for HashMap<K, V, H>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: Storage<(K, V)>,
+    H: BuildHasher,
+{
+    fn eq(&self, other: &VecMap<K, V, S>) -> bool {
+        other == self
+    }
+}
+
+impl<K, V, S> PartialEq<BTreeMap<K, V>> for VecMap<K, V, S>
+where
+    K: Ord,
+    V: PartialEq,
+    S: Storage<(K, V)>,
+{
+    fn eq(&self, other: &BTreeMap<K, V>) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K, V, S> PartialEq<VecMap<K, V, S>> for BTreeMap<K, V>
+where
+    K: Ord,
     V: PartialEq,
+    S: Storage<(K, V)>,
 {
-    fn eq(&self, other: &HashMap<K, V>) -> bool {
-        other.iter().eq(self.iter())
+    fn eq(&self, other: &VecMap<K, V, S>) -> bool {
+        other == self
     }
 }
@@ -0,0 +1,274 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use super::VecMap;
+use crate::{equivalent::Equivalent, storage::Storage};
+
+///A builder for looking up entries in a [`VecMap`] without an owned key, created by [`VecMap::raw_entry`]
+pub struct RawEntryBuilder<'a, K, V, S = Vec<(K, V)>> {
+    pub(super) map: &'a VecMap<K, V, S>,
+}
+
+impl<'a, K, V, S: Storage<(K, V)>> RawEntryBuilder<'a, K, V, S> {
+    ///Looks up the entry with the given key
+    pub fn from_key<Q>(self, k: &Q) -> Option<(&'a K, &'a V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.from_key_with(|key| k.equivalent(key))
+    }
+
+    ///Looks up the first entry for whose key `is_match` returns `true`
+    pub fn from_key_with<F>(self, mut is_match: F) -> Option<(&'a K, &'a V)>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        self.map
+            .vec
+            .as_slice()
+            .iter()
+            .find(|(k, _)| is_match(k))
+            .map(|(k, v)| (k, v))
+    }
+}
+
+impl<K, V, S> core::fmt::Debug for RawEntryBuilder<'_, K, V, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawEntryBuilder").finish()
+    }
+}
+
+///A builder for looking up entries in a [`VecMap`] without an owned key, created by [`VecMap::raw_entry_mut`]
+pub struct RawEntryBuilderMut<'a, K, V, S = Vec<(K, V)>> {
+    pub(super) map: &'a mut VecMap<K, V, S>,
+}
+
+impl<'a, K, V, S: Storage<(K, V)>> RawEntryBuilderMut<'a, K, V, S> {
+    ///Looks up the entry with the given key
+    pub fn from_key<Q>(self, k: &Q) -> RawEntryMut<'a, K, V, S>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.from_key_with(|key| k.equivalent(key))
+    }
+
+    ///Looks up the first entry for whose key `is_match` returns `true`
+    pub fn from_key_with<F>(self, mut is_match: F) -> RawEntryMut<'a, K, V, S>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        match self
+            .map
+            .vec
+            .as_slice()
+            .iter()
+            .position(|(k, _)| is_match(k))
+        {
+            Some(index) => RawEntryMut::Occupied(RawOccupiedEntryMut {
+                index,
+                table: &mut self.map.vec,
+                _marker: PhantomData,
+            }),
+            None => RawEntryMut::Vacant(RawVacantEntryMut {
+                table: &mut self.map.vec,
+                _marker: PhantomData,
+            }),
+        }
+    }
+}
+
+impl<K, V, S> core::fmt::Debug for RawEntryBuilderMut<'_, K, V, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawEntryBuilderMut").finish()
+    }
+}
+
+///A view into a single entry in a [`VecMap`], obtained from [`RawEntryBuilderMut`]
+pub enum RawEntryMut<'a, K, V, S = Vec<(K, V)>> {
+    Occupied(RawOccupiedEntryMut<'a, K, V, S>),
+    Vacant(RawVacantEntryMut<'a, K, V, S>),
+}
+
+impl<'a, K, V, S: Storage<(K, V)>> RawEntryMut<'a, K, V, S> {
+    ///Ensures a value is in the entry by inserting the given pair if empty, and returns mutable references to the
+    ///key and the value in the entry
+    pub fn or_insert(self, default_key: K, default_val: V) -> (&'a mut K, &'a mut V) {
+        match self {
+            RawEntryMut::Occupied(e) => e.into_key_value(),
+            RawEntryMut::Vacant(e) => e.insert(default_key, default_val),
+        }
+    }
+
+    ///Ensures a value is in the entry by inserting the result of `default` if empty, and returns mutable references
+    ///to the key and the value in the entry
+    pub fn or_insert_with<F>(self, default: F) -> (&'a mut K, &'a mut V)
+    where
+        F: FnOnce() -> (K, V),
+    {
+        match self {
+            RawEntryMut::Occupied(e) => e.into_key_value(),
+            RawEntryMut::Vacant(e) => {
+                let (k, v) = default();
+                e.insert(k, v)
+            }
+        }
+    }
+
+    ///Provides in-place mutable access to an occupied entry before any potential inserts into the map
+    #[must_use]
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut K, &mut V),
+    {
+        match self {
+            RawEntryMut::Occupied(mut e) => {
+                let (k, v) = e.get_key_value_mut();
+                f(k, v);
+                RawEntryMut::Occupied(e)
+            }
+            RawEntryMut::Vacant(_) => self,
+        }
+    }
+
+    ///Returns the index of the entry, or the index it will have once inserted if it's vacant
+    pub fn index(&self) -> usize {
+        match self {
+            RawEntryMut::Occupied(e) => e.index(),
+            RawEntryMut::Vacant(e) => e.index(),
+        }
+    }
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug, S: Storage<(K, V)>> core::fmt::Debug
+    for RawEntryMut<'_, K, V, S>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RawEntryMut::Occupied(e) => f.debug_tuple("RawEntryMut").field(e).finish(),
+            RawEntryMut::Vacant(e) => f.debug_tuple("RawEntryMut").field(e).finish(),
+        }
+    }
+}
+
+///A view into an occupied entry in a [`VecMap`], part of the [`RawEntryMut`] enum
+pub struct RawOccupiedEntryMut<'a, K, V, S = Vec<(K, V)>> {
+    index: usize,
+    table: &'a mut S,
+    _marker: PhantomData<&'a mut (K, V)>,
+}
+
+impl<'a, K, V, S: Storage<(K, V)>> RawOccupiedEntryMut<'a, K, V, S> {
+    pub fn key(&self) -> &K {
+        &self.table.as_slice()[self.index].0
+    }
+
+    ///Returns a mutable reference to the key of the entry.
+    ///
+    ///Changing the key so that it's equal to another key in the map will break the uniqueness of the keys
+    pub fn key_mut(&mut self) -> &mut K {
+        &mut self.table.as_mut_slice()[self.index].0
+    }
+
+    ///Converts the entry into a mutable reference to the key, with the same caveat as [`Self::key_mut`]
+    pub fn into_key(self) -> &'a mut K {
+        &mut self.table.as_mut_slice()[self.index].0
+    }
+
+    pub fn get(&self) -> &V {
+        &self.table.as_slice()[self.index].1
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.table.as_mut_slice()[self.index].1
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.table.as_mut_slice()[self.index].1
+    }
+
+    pub fn get_key_value(&self) -> (&K, &V) {
+        let (k, v) = &self.table.as_slice()[self.index];
+        (k, v)
+    }
+
+    pub fn get_key_value_mut(&mut self) -> (&mut K, &mut V) {
+        let (k, v) = &mut self.table.as_mut_slice()[self.index];
+        (k, v)
+    }
+
+    pub fn into_key_value(self) -> (&'a mut K, &'a mut V) {
+        let (k, v) = &mut self.table.as_mut_slice()[self.index];
+        (k, v)
+    }
+
+    ///Sets the value of the entry, keeping its position in the map, and returns the old value
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    ///Sets the key of the entry, and returns the old key
+    pub fn insert_key(&mut self, key: K) -> K {
+        core::mem::replace(self.key_mut(), key)
+    }
+
+    ///Returns the index of the entry in the map
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    ///Removes the entry from the map, shifting all following entries down by one
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    ///Removes the entry from the map, shifting all following entries down by one
+    pub fn remove_entry(self) -> (K, V) {
+        self.table.remove(self.index)
+    }
+
+    ///Removes the entry from the map by swapping it with the last entry, this does not preserve the order of the map
+    pub fn swap_remove_entry(self) -> (K, V) {
+        self.table.swap_remove(self.index)
+    }
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug, S: Storage<(K, V)>> core::fmt::Debug
+    for RawOccupiedEntryMut<'_, K, V, S>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawOccupiedEntryMut")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+///A view into a vacant entry in a [`VecMap`], part of the [`RawEntryMut`] enum
+pub struct RawVacantEntryMut<'a, K, V, S = Vec<(K, V)>> {
+    table: &'a mut S,
+    _marker: PhantomData<&'a mut (K, V)>,
+}
+
+impl<'a, K, V, S: Storage<(K, V)>> RawVacantEntryMut<'a, K, V, S> {
+    ///Appends the given pair to the end of the map, and returns mutable references to it.
+    ///
+    ///The key is not checked, it's up to the caller to make sure it matches the lookup that produced this entry,
+    ///otherwise the uniqueness of the keys will be broken
+    pub fn insert(self, key: K, value: V) -> (&'a mut K, &'a mut V) {
+        self.table.push((key, value));
+        //When we insert a new value it is always last in the vec so this SHOULD be fine
+        let (k, v) = self.table.as_mut_slice().last_mut().unwrap();
+        (k, v)
+    }
+
+    ///Returns the index the entry will have once inserted
+    pub fn index(&self) -> usize {
+        self.table.as_slice().len()
+    }
+}
+
+impl<K, V, S> core::fmt::Debug for RawVacantEntryMut<'_, K, V, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawVacantEntryMut").finish()
+    }
+}
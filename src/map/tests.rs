@@ -1,5 +1,13 @@
 #![allow(unused_imports)]
-use std::cell::RefCell;
+use std::{
+    boxed::Box,
+    cell::RefCell,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[cfg(feature = "arrayvec")]
+use arrayvec::ArrayVec;
 
 use crate::map::Entry::*;
 use crate::map::*;
@@ -784,3 +792,533 @@ fn from_array() {
     // that's a problem!
     let _must_not_require_type_annotation = VecMap::from([(1, 2)]);
 }
+
+#[test]
+fn test_insertion_order() {
+    let mut map = VecMap::new();
+    map.insert(3, 'c');
+    map.insert(1, 'a');
+    map.insert(2, 'b');
+
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [3, 1, 2]);
+}
+
+#[test]
+fn test_overwrite_keeps_position() {
+    let mut map = VecMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+
+    assert_eq!(map.insert(1, 'x'), Some('a'));
+    assert_eq!(
+        map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+        [(1, 'x'), (2, 'b'), (3, 'c')]
+    );
+
+    match map.entry(2) {
+        Vacant(_) => unreachable!(),
+        Occupied(mut e) => assert_eq!(e.insert('y'), 'b'),
+    }
+    assert_eq!(
+        map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+        [(1, 'x'), (2, 'y'), (3, 'c')]
+    );
+
+    let from_duplicates = VecMap::from([(1, 'a'), (2, 'b'), (1, 'c')]);
+    assert_eq!(
+        from_duplicates.into_iter().collect::<Vec<_>>(),
+        [(1, 'c'), (2, 'b')]
+    );
+}
+
+#[test]
+fn test_remove_preserves_order() {
+    let mut map: VecMap<i32, i32> = (0..6).map(|i| (i, i)).collect();
+
+    assert_eq!(map.remove(&1), Some(1));
+    assert_eq!(map.remove_entry(&3), Some((3, 3)));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 2, 4, 5]);
+
+    match map.entry(0) {
+        Vacant(_) => unreachable!(),
+        Occupied(e) => assert_eq!(e.remove(), 0),
+    }
+    match map.entry(4) {
+        Vacant(_) => unreachable!(),
+        Occupied(e) => assert_eq!(e.remove_entry(), (4, 4)),
+    }
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [2, 5]);
+
+    map.insert(0, 0);
+    map.retain(|&k, _| k != 5);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [2, 0]);
+}
+
+#[test]
+fn test_index_access() {
+    let mut map = VecMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+
+    assert_eq!(map.get_index(0), Some((&1, &'a')));
+    assert_eq!(map.get_index(3), None);
+    assert_eq!(map.get_index_of(&3), Some(2));
+    assert_eq!(map.get_index_of(&4), None);
+
+    if let Some((_, v)) = map.get_index_mut(1) {
+        *v = 'x';
+    }
+    assert_eq!(map[&2], 'x');
+
+    assert_eq!(map.first(), Some((&1, &'a')));
+    assert_eq!(map.last(), Some((&3, &'c')));
+
+    map.swap_indices(0, 2);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [3, 2, 1]);
+
+    map.move_index(0, 2);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [2, 1, 3]);
+    map.move_index(2, 0);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [3, 2, 1]);
+
+    assert_eq!(map.swap_remove_index(0), Some((3, 'c')));
+    assert_eq!(map.swap_remove_index(5), None);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 2]);
+
+    assert_eq!(map.pop(), Some((2, 'x')));
+    assert_eq!(map.pop(), Some((1, 'a')));
+    assert_eq!(map.pop(), None);
+    assert_eq!(map.first(), None);
+}
+
+#[test]
+fn test_entry_index() {
+    let mut map = VecMap::from([(1, 'a'), (2, 'b')]);
+
+    assert_eq!(map.entry(2).index(), 1);
+    match map.entry(5) {
+        Occupied(_) => unreachable!(),
+        Vacant(e) => {
+            assert_eq!(e.index(), 2);
+            e.insert('e');
+        }
+    }
+    assert_eq!(map.get_index_of(&5), Some(2));
+}
+
+#[test]
+fn test_swap_and_shift_remove() {
+    let mut map: VecMap<i32, i32> = (0..6).map(|i| (i, i * 10)).collect();
+
+    assert_eq!(map.swap_remove(&1), Some(10));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 5, 2, 3, 4]);
+    assert_eq!(map.swap_remove_entry(&0), Some((0, 0)));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [4, 5, 2, 3]);
+    assert_eq!(map.swap_remove(&0), None);
+
+    assert_eq!(map.shift_remove(&5), Some(50));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [4, 2, 3]);
+    assert_eq!(map.shift_remove_entry(&4), Some((4, 40)));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(map.shift_remove_entry(&4), None);
+
+    map.insert(7, 70);
+    assert_eq!(map.shift_remove_index(0), Some((2, 20)));
+    assert_eq!(map.shift_remove_index(2), None);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [3, 7]);
+}
+
+#[test]
+fn test_occupied_entry_swap_and_shift_remove() {
+    let mut map: VecMap<i32, i32> = (0..4).map(|i| (i, i)).collect();
+
+    match map.entry(0) {
+        Vacant(_) => unreachable!(),
+        Occupied(e) => assert_eq!(e.swap_remove(), 0),
+    }
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [3, 1, 2]);
+
+    match map.entry(3) {
+        Vacant(_) => unreachable!(),
+        Occupied(e) => assert_eq!(e.shift_remove_entry(), (3, 3)),
+    }
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 2]);
+}
+
+#[test]
+fn test_try_reserve() {
+    let mut map: VecMap<u8, u8> = VecMap::try_with_capacity(16).unwrap();
+    assert!(map.capacity() >= 16);
+
+    assert!(map.try_reserve(usize::MAX).is_err());
+    assert!(VecMap::<u8, u8>::try_with_capacity(usize::MAX).is_err());
+    assert!(map.try_reserve(32).is_ok());
+    assert!(map.capacity() >= 32);
+}
+
+#[test]
+fn test_try_insert_alloc() {
+    let mut map = VecMap::new();
+    assert_eq!(map.try_insert_alloc(1, 'a'), Ok(None));
+    assert_eq!(map.try_insert_alloc(2, 'b'), Ok(None));
+    assert_eq!(map.try_insert_alloc(1, 'c'), Ok(Some('a')));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 2]);
+
+    map.try_extend([(3, 'd'), (2, 'e')]).unwrap();
+    assert_eq!(
+        map.into_iter().collect::<Vec<_>>(),
+        [(1, 'c'), (2, 'e'), (3, 'd')]
+    );
+
+    let map = VecMap::try_from_iter([(1, 1), (2, 2), (1, 3)]).unwrap();
+    assert_eq!(map, VecMap::from([(1, 3), (2, 2)]));
+}
+
+#[test]
+#[cfg(feature = "arrayvec")]
+fn test_try_insert_full_storage() {
+    let mut map: VecMap<i32, i32, ArrayVec<(i32, i32), 2>> = VecMap::default();
+    assert_eq!(map.try_insert(1, 1), Ok(None));
+    assert_eq!(map.try_insert(2, 2), Ok(None));
+    assert_eq!(map.try_insert(3, 3).unwrap_err().element(), (3, 3));
+    // Overwriting an existing key doesn't need any space
+    assert_eq!(map.try_insert(1, 10), Ok(Some(1)));
+
+    assert!(map.entry(3).or_try_insert(3).is_err());
+    assert_eq!(map.entry(2).or_try_insert(20), Ok(&mut 2));
+    match map.entry(4) {
+        Occupied(_) => unreachable!(),
+        Vacant(e) => assert_eq!(e.try_insert(4).unwrap_err().element(), (4, 4)),
+    }
+    assert_eq!(map.len(), 2);
+
+    let mut map = VecMap::new();
+    assert_eq!(map.try_insert(1, 1), Ok(None));
+}
+
+#[test]
+fn test_raw_entry() {
+    let map = VecMap::from([("a".to_string(), 1), ("b".to_string(), 2)]);
+
+    assert_eq!(map.raw_entry().from_key("b"), Some((&"b".to_string(), &2)));
+    assert_eq!(map.raw_entry().from_key("c"), None);
+    assert_eq!(
+        map.raw_entry().from_key_with(|k| k.starts_with('a')),
+        Some((&"a".to_string(), &1))
+    );
+}
+
+#[test]
+fn test_raw_entry_mut() {
+    let mut map: VecMap<String, i32> = VecMap::new();
+
+    for word in ["a", "b", "a", "c", "a"] {
+        let (_, v) = map
+            .raw_entry_mut()
+            .from_key(word)
+            .and_modify(|_, v| *v += 1)
+            .or_insert_with(|| (word.to_string(), 1));
+        assert!(*v > 0);
+    }
+    assert_eq!(map["a"], 3);
+    assert_eq!(map["b"], 1);
+    assert_eq!(map.keys().cloned().collect::<Vec<_>>(), ["a", "b", "c"]);
+
+    match map.raw_entry_mut().from_key_with(|k| k == "b") {
+        RawEntryMut::Vacant(_) => unreachable!(),
+        RawEntryMut::Occupied(mut e) => {
+            assert_eq!(e.index(), 1);
+            assert_eq!(e.insert(10), 1);
+            assert_eq!(e.insert_key("d".to_string()), "b");
+        }
+    }
+    assert_eq!(map["d"], 10);
+
+    match map.raw_entry_mut().from_key("e") {
+        RawEntryMut::Occupied(_) => unreachable!(),
+        RawEntryMut::Vacant(e) => {
+            assert_eq!(e.index(), 3);
+            e.insert("e".to_string(), 5);
+        }
+    }
+    assert_eq!(map.get_index_of("e"), Some(3));
+
+    match map.raw_entry_mut().from_key("a") {
+        RawEntryMut::Vacant(_) => unreachable!(),
+        RawEntryMut::Occupied(e) => assert_eq!(e.remove_entry(), ("a".to_string(), 3)),
+    }
+    assert_eq!(map.keys().cloned().collect::<Vec<_>>(), ["d", "c", "e"]);
+}
+
+#[test]
+fn test_entry_ref() {
+    let mut map: VecMap<String, i32> = VecMap::new();
+
+    for word in ["a", "b", "a", "c", "a"] {
+        *map.entry_ref(word).or_insert(0) += 1;
+    }
+    assert_eq!(map["a"], 3);
+    assert_eq!(map["b"], 1);
+    assert_eq!(map.keys().cloned().collect::<Vec<_>>(), ["a", "b", "c"]);
+
+    match map.entry_ref("b") {
+        EntryRef::Vacant(_) => unreachable!(),
+        EntryRef::Occupied(mut e) => {
+            assert_eq!(e.index(), 1);
+            assert_eq!(e.insert(5), 1);
+        }
+    }
+    assert_eq!(map["b"], 5);
+
+    match map.entry_ref("d") {
+        EntryRef::Occupied(_) => unreachable!(),
+        EntryRef::Vacant(e) => {
+            assert_eq!(e.key(), "d");
+            assert_eq!(e.index(), 3);
+            assert_eq!(*e.insert(7), 7);
+        }
+    }
+    assert_eq!(map["d"], 7);
+
+    let v = map
+        .entry_ref("e")
+        .and_modify(|v| *v += 1)
+        .or_insert_with_key(|k| k.len() as i32);
+    assert_eq!(*v, 1);
+    assert_eq!(*map.entry_ref("e").and_modify(|v| *v += 1).or_default(), 2);
+}
+
+#[test]
+fn test_get_many_mut() {
+    let mut map = VecMap::from([(1, 10), (2, 20), (3, 30)]);
+
+    let [a, b] = map.get_many_mut([&1, &3]).unwrap();
+    std::mem::swap(a, b);
+    assert_eq!(map[&1], 30);
+    assert_eq!(map[&3], 10);
+
+    let [c, b, a] = map.get_many_mut([&3, &2, &1]).unwrap();
+    assert_eq!((*a, *b, *c), (30, 20, 10));
+
+    assert!(map.get_many_mut([&1, &4]).is_none());
+    assert!(map.get_many_mut([&2, &2]).is_none());
+    assert!(map.get_many_mut::<i32, 0>([]).is_some());
+
+    let [a, b, c] = unsafe { map.get_many_unchecked_mut([&3, &2, &1]) }.unwrap();
+    *a += *b + *c;
+    assert_eq!(map[&3], 60);
+    assert!(unsafe { map.get_many_unchecked_mut([&5]) }.is_none());
+}
+
+#[test]
+fn test_extract_if() {
+    let mut map: VecMap<i32, i32> = (0..8).map(|x| (x, x * 10)).collect();
+
+    let extracted: Vec<_> = map.extract_if(|k, _| k % 2 == 0).collect();
+    assert_eq!(extracted, [(0, 0), (2, 20), (4, 40), (6, 60)]);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 3, 5, 7]);
+
+    // Dropping the iterator early keeps the entries it hasn't visited
+    let first = map
+        .extract_if(|_, v| {
+            *v += 1;
+            true
+        })
+        .next();
+    assert_eq!(first, Some((1, 11)));
+    assert_eq!(
+        map.into_iter().collect::<Vec<_>>(),
+        [(3, 30), (5, 50), (7, 70)]
+    );
+}
+
+#[test]
+fn test_extract_if_partial_and_storages() {
+    // Kept entries before and after the point where the iterator is dropped are shifted back together
+    let mut map: VecMap<i32, String> = (0..10).map(|x| (x, x.to_string())).collect();
+    let mut iter = map.extract_if(|k, _| k % 3 == 0);
+    assert_eq!(iter.next(), Some((0, String::from("0"))));
+    assert_eq!(iter.next(), Some((3, String::from("3"))));
+    assert_eq!(iter.size_hint(), (0, Some(6)));
+    drop(iter);
+    assert_eq!(
+        map.keys().copied().collect::<Vec<_>>(),
+        [1, 2, 4, 5, 6, 7, 8, 9]
+    );
+
+    #[cfg(feature = "arrayvec")]
+    {
+        let mut inline: VecMap<i32, i32, ArrayVec<(i32, i32), 6>> =
+            (0..6).map(|x| (x, x)).collect();
+        assert_eq!(inline.extract_if(|k, _| *k > 2).count(), 3);
+        assert_eq!(inline.keys().copied().collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    let mut boxed: VecMap<i32, i32, Box<[(i32, i32)]>> = (0..6).map(|x| (x, x)).collect();
+    assert_eq!(boxed.extract_if(|k, _| k % 2 == 1).next(), Some((1, 1)));
+    assert_eq!(boxed.keys().copied().collect::<Vec<_>>(), [0, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_extract_if_panic() {
+    let mut map: VecMap<i32, String> = (0..6).map(|x| (x, x.to_string())).collect();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        for _ in map.extract_if(|k, _| {
+            assert!(*k != 3);
+            k % 2 == 0
+        }) {}
+    }));
+    assert!(result.is_err());
+    // The entry the predicate panicked on and the ones after it are kept
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 3, 4, 5]);
+}
+
+#[test]
+fn test_other_storages() {
+    #[cfg(feature = "arrayvec")]
+    {
+        let mut inline: VecMap<&str, i32, ArrayVec<(&str, i32), 3>> = VecMap::default();
+        inline.insert("a", 1);
+        inline.insert("b", 2);
+        *inline.entry("a").or_insert(0) += 10;
+        assert_eq!(inline.insert("c", 3), None);
+        assert_eq!(inline.capacity(), 3);
+        assert_eq!(inline.remove(&"b"), Some(2));
+        assert_eq!(inline.iter().collect::<Vec<_>>(), [(&"a", &11), (&"c", &3)]);
+    }
+
+    let mut boxed: VecMap<i32, char, Box<[(i32, char)]>> =
+        VecMap::with_storage(vec![(1, 'x')].into_boxed_slice());
+    assert!(boxed.is_empty());
+    boxed.extend([(1, 'a'), (2, 'b'), (3, 'c'), (1, 'd')]);
+    assert_eq!(boxed.len(), 3);
+    assert_eq!(boxed.capacity(), 3);
+    assert_eq!(boxed[&1], 'd');
+    boxed.retain(|k, _| *k != 2);
+    assert_eq!(boxed.drain().collect::<Vec<_>>(), [(1, 'd'), (3, 'c')]);
+    assert!(boxed.is_empty());
+}
+
+#[test]
+fn test_equivalent_lookup() {
+    use crate::equivalent::Equivalent;
+
+    struct Pair<'a>(&'a str, u32);
+
+    impl Equivalent<(String, u32)> for Pair<'_> {
+        fn equivalent(&self, key: &(String, u32)) -> bool {
+            self.0 == key.0 && self.1 == key.1
+        }
+    }
+
+    let mut map: VecMap<(String, u32), i32> = VecMap::new();
+    map.insert(("a".to_string(), 1), 10);
+    map.insert(("a".to_string(), 2), 20);
+
+    assert_eq!(map.get(&Pair("a", 2)), Some(&20));
+    assert!(map.contains_key(&Pair("a", 1)));
+    assert!(!map.contains_key(&Pair("b", 1)));
+    assert_eq!(map.get_index_of(&Pair("a", 2)), Some(1));
+    assert_eq!(map[&Pair("a", 1)], 10);
+    *map.get_mut(&Pair("a", 1)).unwrap() += 1;
+    assert_eq!(
+        map.get_key_value(&Pair("a", 1)),
+        Some((&("a".to_string(), 1), &11))
+    );
+    assert!(map.raw_entry().from_key(&Pair("a", 2)).is_some());
+    assert_eq!(map.remove(&Pair("a", 1)), Some(11));
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn test_hash_ignores_order() {
+    use std::collections::HashSet;
+    use std::hash::{BuildHasher, RandomState};
+
+    let state = RandomState::new();
+    let a = VecMap::from([(1, "a"), (2, "b"), (3, "c")]);
+    let b = VecMap::from([(3, "c"), (1, "a"), (2, "b")]);
+    let c = VecMap::from([(1, "a"), (2, "b"), (3, "d")]);
+    assert_eq!(a, b);
+    assert_eq!(state.hash_one(&a), state.hash_one(&b));
+    assert_ne!(state.hash_one(&a), state.hash_one(&c));
+    // A subset isn't equal, and hashes differently
+    let subset = VecMap::from([(1, "a")]);
+    assert_ne!(a, subset);
+    assert_ne!(subset, a);
+    assert_ne!(state.hash_one(&a), state.hash_one(&subset));
+
+    let mut maps = HashSet::new();
+    assert!(maps.insert(a));
+    assert!(!maps.insert(b));
+    assert!(maps.insert(c));
+
+    // Usable as keys in other maps
+    let mut nested = VecMap::new();
+    nested.insert(VecMap::from([('x', 1), ('y', 2)]), 1);
+    nested.insert(VecMap::from([('y', 2), ('x', 1)]), 2);
+    assert_eq!(nested.len(), 1);
+}
+
+#[test]
+fn test_eq_checks_length_and_is_symmetric() {
+    let small = VecMap::from([(1, 'a')]);
+    let big = VecMap::from([(1, 'a'), (2, 'b')]);
+    assert_ne!(small, big);
+    assert_ne!(big, small);
+    assert_eq!(big, VecMap::from([(2, 'b'), (1, 'a')]));
+    assert_ne!(big, VecMap::from([(2, 'b'), (1, 'x')]));
+}
+
+#[test]
+fn test_eq_with_std_maps() {
+    use std::collections::BTreeMap;
+
+    let map: VecMap<i32, i32> = (0..20).rev().map(|i| (i, i * 2)).collect();
+    let btree: BTreeMap<i32, i32> = (0..20).map(|i| (i, i * 2)).collect();
+
+    assert!(map == btree);
+    assert!(btree == map);
+
+    let mut different = btree.clone();
+    different.insert(0, 1);
+    assert!(map != different);
+    assert!(different != map);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_eq_with_hash_maps() {
+    use std::collections::HashMap;
+
+    let map: VecMap<i32, i32> = (0..20).rev().map(|i| (i, i * 2)).collect();
+    let hash: HashMap<i32, i32> = (0..20).map(|i| (i, i * 2)).collect();
+
+    assert!(map == hash);
+    assert!(hash == map);
+
+    let shorter: HashMap<i32, i32> = (0..19).map(|i| (i, i * 2)).collect();
+    assert!(map != shorter);
+    assert!(shorter != map);
+}
+
+#[test]
+fn test_std_conversions() {
+    use std::collections::BTreeMap;
+
+    let map = VecMap::from(vec![(1, 'a'), (2, 'b'), (1, 'c')]);
+    assert_eq!(map.iter().collect::<Vec<_>>(), [(&1, &'c'), (&2, &'b')]);
+    assert_eq!(Vec::from(map.clone()), [(1, 'c'), (2, 'b')]);
+
+    let btree = BTreeMap::from([(3, 'x'), (1, 'y'), (2, 'z')]);
+    let sorted = VecMap::from(btree.clone());
+    assert_eq!(sorted.keys().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(BTreeMap::from(sorted), btree);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_hash_map_conversions() {
+    use std::collections::HashMap;
+
+    let map = VecMap::from([(1, 'c'), (2, 'b')]);
+    let hash: HashMap<_, _> = map.clone().into();
+    assert_eq!(hash, HashMap::from([(1, 'c'), (2, 'b')]));
+    assert_eq!(VecMap::from(hash), map);
+}
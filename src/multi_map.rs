@@ -0,0 +1,417 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::{
+    equivalent::Equivalent,
+    map::{IntoIter, Iter, IterMut, Values},
+    storage::Extractor,
+};
+
+#[cfg(test)]
+mod tests;
+
+///A map that can hold several values for the same key, using the same `Vec<(K, V)>` layout as [`crate::map::VecMap`].
+///
+///Inserting never overwrites, every pair is appended to the end, so the values of a key keep their insertion order.
+///Single value lookups like [`VecMultiMap::get`] return the first value inserted for the key.
+#[derive(Clone)]
+pub struct VecMultiMap<K, V> {
+    vec: Vec<(K, V)>,
+}
+
+impl<K, V> Default for VecMultiMap<K, V> {
+    fn default() -> Self {
+        Self { vec: Vec::new() }
+    }
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug> core::fmt::Debug for VecMultiMap<K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(self.vec.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
+impl<K, V> VecMultiMap<K, V> {
+    pub const fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    ///Returns the number of pairs in the map, counting every value of a key
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    ///Returns all pairs of the map as a slice, in insertion order
+    pub fn as_slice(&self) -> &[(K, V)] {
+        &self.vec
+    }
+
+    ///An iterator over all pairs in insertion order, keys with several values are yielded once per value
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.vec.iter(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: self.vec.iter_mut(),
+        }
+    }
+
+    ///An iterator over all values in insertion order
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values {
+            inner: self.vec.iter(),
+        }
+    }
+}
+
+impl<K: Eq, V> VecMultiMap<K, V> {
+    ///Appends the pair to the end of the map, the key's existing values are kept
+    pub fn insert(&mut self, key: K, value: V) {
+        self.vec.push((key, value));
+    }
+
+    ///Returns the first value inserted for the key
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.vec
+            .iter()
+            .find(|(k, _)| key.equivalent(k))
+            .map(|(_, v)| v)
+    }
+
+    ///Returns the first value inserted for the key
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.vec
+            .iter_mut()
+            .find(|(k, _)| key.equivalent(k))
+            .map(|(_, v)| v)
+    }
+
+    ///An iterator over all values of the key, in insertion order
+    pub fn get_all<'a, 'q, Q>(&'a self, key: &'q Q) -> GetAll<'a, 'q, K, Q, V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        GetAll {
+            key,
+            inner: self.vec.iter(),
+        }
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.vec.iter().any(|(k, _)| key.equivalent(k))
+    }
+
+    ///Returns the number of values stored for the key
+    pub fn count<Q>(&self, key: &Q) -> usize
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.vec.iter().filter(|(k, _)| key.equivalent(k)).count()
+    }
+
+    ///Removes the first value inserted for the key, shifting all following pairs down by one
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let index = self.vec.iter().position(|(k, _)| key.equivalent(k))?;
+        Some(self.vec.remove(index).1)
+    }
+
+    ///Removes all values of the key and returns them in insertion order, the order of the remaining pairs is
+    ///preserved
+    pub fn remove_all<Q>(&mut self, key: &Q) -> Vec<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        //Extracting in place shifts the kept pairs back in a single pass, only the returned values are allocated
+        let mut extractor = Extractor::new(&mut self.vec);
+        core::iter::from_fn(|| extractor.next(|(k, _)| key.equivalent(k)))
+            .map(|(_, v)| v)
+            .collect()
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        Entry {
+            key,
+            table: &mut self.vec,
+        }
+    }
+
+    ///An iterator over the distinct keys, in the order of their first insertion.
+    ///
+    ///Every step searches the preceding pairs for the key, so exhausting it is quadratic in the length of the map
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys {
+            slice: &self.vec,
+            index: 0,
+        }
+    }
+
+    ///An iterator over the distinct keys along with an iterator over all of their values, in the order of the first
+    ///insertion of each key
+    pub fn iter_grouped(&self) -> Grouped<'_, K, V> {
+        Grouped { keys: self.keys() }
+    }
+
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let mut f = f;
+        self.vec.retain_mut(|i| f(&i.0, &mut i.1));
+    }
+}
+
+impl<K: Eq, V> FromIterator<(K, V)> for VecMultiMap<K, V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self {
+            vec: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<K: Eq, V> Extend<(K, V)> for VecMultiMap<K, V> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        self.vec.extend(iter);
+    }
+}
+
+impl<K: Eq, V, const N: usize> From<[(K, V); N]> for VecMultiMap<K, V> {
+    fn from(arr: [(K, V); N]) -> Self {
+        Self { vec: arr.into() }
+    }
+}
+
+impl<K: Eq, V> IntoIterator for VecMultiMap<K, V> {
+    type Item = (K, V);
+
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.vec.into_iter(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a VecMultiMap<K, V> {
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut VecMultiMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+///A view into the values of a single key in a [`VecMultiMap`], created by [`VecMultiMap::entry`]
+pub struct Entry<'a, K, V> {
+    key: K,
+    table: &'a mut Vec<(K, V)>,
+}
+
+impl<'a, K: Eq, V> Entry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    ///Appends a value for the key to the end of the map and returns a mutable reference to it
+    pub fn append(self, value: V) -> &'a mut V {
+        self.table.push((self.key, value));
+        //The new pair is always last in the vec
+        &mut self.table.last_mut().unwrap().1
+    }
+
+    ///Returns the first value of the key, appending `default` if the key has no values
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    ///Returns the first value of the key, appending the result of `default` if the key has no values
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self.table.iter().position(|(k, _)| *k == self.key) {
+            Some(index) => &mut self.table[index].1,
+            None => self.append(default()),
+        }
+    }
+
+    ///Returns the number of values the key already has
+    pub fn count(&self) -> usize {
+        self.table.iter().filter(|(k, _)| *k == self.key).count()
+    }
+}
+
+impl<K: core::fmt::Debug, V> core::fmt::Debug for Entry<'_, K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Entry").field(&self.key).finish()
+    }
+}
+
+///An iterator over the values of a single key, created by [`VecMultiMap::get_all`]
+pub struct GetAll<'a, 'q, K, Q: ?Sized, V> {
+    key: &'q Q,
+    inner: core::slice::Iter<'a, (K, V)>,
+}
+
+impl<'a, K, Q: Equivalent<K> + ?Sized, V> Iterator for GetAll<'a, '_, K, Q, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.key;
+        self.inner.find(|(k, _)| key.equivalent(k)).map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<K, Q: ?Sized, V> Clone for GetAll<'_, '_, K, Q, V> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key,
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<K: core::fmt::Debug, Q: ?Sized, V: core::fmt::Debug> core::fmt::Debug
+    for GetAll<'_, '_, K, Q, V>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GetAll")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+///An iterator over the distinct keys of a [`VecMultiMap`], created by [`VecMultiMap::keys`]
+#[derive(Clone, Debug)]
+pub struct Keys<'a, K, V> {
+    slice: &'a [(K, V)],
+    index: usize,
+}
+
+impl<'a, K: Eq, V> Keys<'a, K, V> {
+    fn next_index(&mut self) -> Option<usize> {
+        while let Some((key, _)) = self.slice.get(self.index) {
+            let index = self.index;
+            self.index += 1;
+            if !self.slice[..index].iter().any(|(k, _)| k == key) {
+                return Some(index);
+            }
+        }
+        None
+    }
+}
+
+impl<'a, K: Eq, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next_index()?;
+        Some(&self.slice[index].0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        //The remaining pairs may all repeat keys that were already yielded
+        (0, Some(self.slice.len() - self.index))
+    }
+}
+
+///An iterator over the distinct keys of a [`VecMultiMap`] and their values, created by
+///[`VecMultiMap::iter_grouped`]
+#[derive(Clone, Debug)]
+pub struct Grouped<'a, K, V> {
+    keys: Keys<'a, K, V>,
+}
+
+impl<'a, K: Eq, V> Iterator for Grouped<'a, K, V> {
+    type Item = (&'a K, Group<'a, K, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.keys.next_index()?;
+        let slice = self.keys.slice;
+        let key = &slice[index].0;
+        //All values of the key are at or after its first occurrence
+        Some((
+            key,
+            Group {
+                key,
+                inner: slice[index..].iter(),
+            },
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+///An iterator over the values of a single key, yielded by [`Grouped`]
+#[derive(Clone, Debug)]
+pub struct Group<'a, K, V> {
+    key: &'a K,
+    inner: core::slice::Iter<'a, (K, V)>,
+}
+
+impl<'a, K: Eq, V> Iterator for Group<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.key;
+        self.inner.find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
@@ -0,0 +1,104 @@
+use std::{string::String, vec::Vec};
+
+use crate::multi_map::*;
+
+fn sample() -> VecMultiMap<&'static str, i32> {
+    VecMultiMap::from([("a", 1), ("b", 2), ("a", 3), ("c", 4), ("b", 5)])
+}
+
+#[test]
+fn test_insert_get() {
+    let mut map = VecMultiMap::new();
+    map.insert("a", 1);
+    map.insert("a", 2);
+    map.insert("b", 3);
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get("a"), Some(&1));
+    assert_eq!(map.get("c"), None);
+    assert_eq!(map.count("a"), 2);
+    assert!(map.contains_key("b"));
+
+    *map.get_mut("a").unwrap() = 10;
+    assert_eq!(map.as_slice(), &[("a", 10), ("a", 2), ("b", 3)]);
+}
+
+#[test]
+fn test_get_all() {
+    let map = sample();
+    assert_eq!(map.get_all("a").collect::<Vec<_>>(), vec![&1, &3]);
+    assert_eq!(map.get_all("b").collect::<Vec<_>>(), vec![&2, &5]);
+    assert_eq!(map.get_all("d").count(), 0);
+
+    let map: VecMultiMap<String, i32> = [(String::from("x"), 1), (String::from("x"), 2)]
+        .into_iter()
+        .collect();
+    assert_eq!(map.get_all("x").copied().collect::<Vec<_>>(), vec![1, 2]);
+}
+
+#[test]
+fn test_remove() {
+    let mut map = sample();
+    assert_eq!(map.remove("a"), Some(1));
+    assert_eq!(map.get("a"), Some(&3));
+
+    assert_eq!(map.remove_all("b"), vec![2, 5]);
+    assert_eq!(map.remove_all("b"), Vec::<i32>::new());
+    assert_eq!(map.as_slice(), &[("a", 3), ("c", 4)]);
+}
+
+#[test]
+fn test_entry() {
+    let mut map = sample();
+    *map.entry("a").append(6) += 1;
+    assert_eq!(map.get_all("a").collect::<Vec<_>>(), vec![&1, &3, &7]);
+
+    assert_eq!(*map.entry("b").or_insert(0), 2);
+    assert_eq!(*map.entry("d").or_insert(8), 8);
+    assert_eq!(map.entry("d").count(), 1);
+    assert_eq!(map.len(), 7);
+}
+
+#[test]
+fn test_keys_and_groups() {
+    let map = sample();
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec![&"a", &"b", &"c"]);
+    assert_eq!(
+        map.values().copied().collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+
+    let groups: Vec<(&str, Vec<i32>)> = map
+        .iter_grouped()
+        .map(|(k, values)| (*k, values.copied().collect()))
+        .collect();
+    assert_eq!(
+        groups,
+        vec![("a", vec![1, 3]), ("b", vec![2, 5]), ("c", vec![4])]
+    );
+    assert_eq!(VecMultiMap::<i32, i32>::new().iter_grouped().count(), 0);
+
+    let map = VecMultiMap::from([(1, 'a'), (1, 'b')]);
+    let mut keys = map.keys();
+    assert_eq!(keys.next(), Some(&1));
+    assert_eq!(keys.size_hint(), (0, Some(1)));
+    assert_eq!(keys.next(), None);
+    let mut groups = map.iter_grouped();
+    assert!(groups.next().is_some());
+    assert_eq!(groups.size_hint(), (0, Some(1)));
+    assert!(groups.next().is_none());
+}
+
+#[test]
+fn test_iter_retain() {
+    let mut map = sample();
+    for (_, v) in &mut map {
+        *v *= 10;
+    }
+    map.retain(|k, v| *k != "c" && *v != 30);
+    map.extend([("c", 1)]);
+    assert_eq!(
+        map.into_iter().collect::<Vec<_>>(),
+        vec![("a", 10), ("b", 20), ("b", 50), ("c", 1)]
+    );
+}
@@ -0,0 +1,371 @@
+//!Parallel iterators over [`VecMap`] and [`VecSet`], built on top of the parallel slice and [`Vec`] iterators.
+//!
+//!Collecting or extending from a parallel iterator gathers the items in parallel, the deduplication that follows is
+//!sequential, and keeps the same ordering rules as [`Extend`].
+
+use alloc::vec::Vec;
+
+use ::rayon::{
+    iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer},
+    prelude::*,
+};
+
+use crate::{map::VecMap, set::VecSet, storage::Storage};
+
+#[cfg(test)]
+mod tests;
+
+///A parallel iterator over the entries of a [`VecMap`], created by [`VecMap::par_iter`]
+#[derive(Debug)]
+pub struct ParIter<'a, K, V> {
+    inner: ::rayon::slice::Iter<'a, (K, V)>,
+}
+
+impl<'a, K: Sync, V: Sync> ParallelIterator for ParIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.map(|(k, v)| (k, v)).drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.inner.len())
+    }
+}
+
+impl<K: Sync, V: Sync> IndexedParallelIterator for ParIter<'_, K, V> {
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.map(|(k, v)| (k, v)).drive(consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.inner.map(|(k, v)| (k, v)).with_producer(callback)
+    }
+}
+
+///A parallel iterator over the entries of a [`VecMap`] with mutable values, created by [`VecMap::par_iter_mut`]
+#[derive(Debug)]
+pub struct ParIterMut<'a, K, V> {
+    inner: ::rayon::slice::IterMut<'a, (K, V)>,
+}
+
+impl<'a, K: Sync + Send, V: Send> ParallelIterator for ParIterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.map(|(k, v)| (&*k, v)).drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.inner.len())
+    }
+}
+
+impl<K: Sync + Send, V: Send> IndexedParallelIterator for ParIterMut<'_, K, V> {
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.map(|(k, v)| (&*k, v)).drive(consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.inner.map(|(k, v)| (&*k, v)).with_producer(callback)
+    }
+}
+
+///A parallel iterator over the mutable values of a [`VecMap`], created by [`VecMap::par_values_mut`]
+#[derive(Debug)]
+pub struct ParValuesMut<'a, K, V> {
+    inner: ::rayon::slice::IterMut<'a, (K, V)>,
+}
+
+impl<'a, K: Send, V: Send> ParallelIterator for ParValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.map(|(_, v)| v).drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.inner.len())
+    }
+}
+
+impl<K: Send, V: Send> IndexedParallelIterator for ParValuesMut<'_, K, V> {
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.map(|(_, v)| v).drive(consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.inner.map(|(_, v)| v).with_producer(callback)
+    }
+}
+
+///An owning parallel iterator over the entries of a [`VecMap`]
+#[derive(Debug)]
+pub struct IntoParIter<K, V> {
+    inner: ::rayon::vec::IntoIter<(K, V)>,
+}
+
+impl<K: Send, V: Send> ParallelIterator for IntoParIter<K, V> {
+    type Item = (K, V);
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.inner.len())
+    }
+}
+
+impl<K: Send, V: Send> IndexedParallelIterator for IntoParIter<K, V> {
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.drive(consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.inner.with_producer(callback)
+    }
+}
+
+impl<K, V, S> VecMap<K, V, S>
+where
+    K: Eq,
+    S: Storage<(K, V)>,
+{
+    ///Returns a parallel iterator over the entries of the map
+    pub fn par_iter(&self) -> ParIter<'_, K, V>
+    where
+        K: Sync,
+        V: Sync,
+    {
+        ParIter {
+            inner: self.vec.as_slice().par_iter(),
+        }
+    }
+
+    ///Returns a parallel iterator over the entries of the map, with mutable references to the values
+    pub fn par_iter_mut(&mut self) -> ParIterMut<'_, K, V>
+    where
+        K: Sync + Send,
+        V: Send,
+    {
+        ParIterMut {
+            inner: self.vec.as_mut_slice().par_iter_mut(),
+        }
+    }
+
+    ///Returns a parallel iterator over mutable references to the values of the map
+    pub fn par_values_mut(&mut self) -> ParValuesMut<'_, K, V>
+    where
+        K: Send,
+        V: Send,
+    {
+        ParValuesMut {
+            inner: self.vec.as_mut_slice().par_iter_mut(),
+        }
+    }
+}
+
+impl<K: Eq + Send, V: Send> IntoParallelIterator for VecMap<K, V> {
+    type Iter = IntoParIter<K, V>;
+
+    type Item = (K, V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        IntoParIter {
+            inner: self.vec.into_par_iter(),
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoParallelIterator for &'a VecMap<K, V, S>
+where
+    K: Eq + Sync,
+    V: Sync,
+    S: Storage<(K, V)>,
+{
+    type Iter = ParIter<'a, K, V>;
+
+    type Item = (&'a K, &'a V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter()
+    }
+}
+
+impl<'a, K, V, S> IntoParallelIterator for &'a mut VecMap<K, V, S>
+where
+    K: Eq + Sync + Send,
+    V: Send,
+    S: Storage<(K, V)>,
+{
+    type Iter = ParIterMut<'a, K, V>;
+
+    type Item = (&'a K, &'a mut V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter_mut()
+    }
+}
+
+impl<K, V, S> ParallelExtend<(K, V)> for VecMap<K, V, S>
+where
+    K: Eq + Send,
+    V: Send,
+    S: Storage<(K, V)>,
+{
+    fn par_extend<I: IntoParallelIterator<Item = (K, V)>>(&mut self, par_iter: I) {
+        let vec: Vec<_> = par_iter.into_par_iter().collect();
+        self.extend(vec);
+    }
+}
+
+impl<K, V, S> FromParallelIterator<(K, V)> for VecMap<K, V, S>
+where
+    K: Eq + Send,
+    V: Send,
+    S: Storage<(K, V)> + Default,
+{
+    fn from_par_iter<I: IntoParallelIterator<Item = (K, V)>>(par_iter: I) -> Self {
+        let mut map = Self::default();
+        map.par_extend(par_iter);
+        map
+    }
+}
+
+///A parallel iterator over the values of a [`VecSet`], created by [`VecSet::par_iter`]
+#[derive(Debug)]
+pub struct SetParIter<'a, T> {
+    inner: ::rayon::slice::Iter<'a, T>,
+}
+
+impl<'a, T: Sync> ParallelIterator for SetParIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.inner.len())
+    }
+}
+
+impl<T: Sync> IndexedParallelIterator for SetParIter<'_, T> {
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.drive(consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.inner.with_producer(callback)
+    }
+}
+
+///An owning parallel iterator over the values of a [`VecSet`]
+#[derive(Debug)]
+pub struct SetIntoParIter<T> {
+    inner: ::rayon::vec::IntoIter<T>,
+}
+
+impl<T: Send> ParallelIterator for SetIntoParIter<T> {
+    type Item = T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.inner.len())
+    }
+}
+
+impl<T: Send> IndexedParallelIterator for SetIntoParIter<T> {
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.inner.drive(consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.inner.with_producer(callback)
+    }
+}
+
+impl<T, S: Storage<T>> VecSet<T, S> {
+    ///Returns a parallel iterator over the values of the set
+    pub fn par_iter(&self) -> SetParIter<'_, T>
+    where
+        T: Sync,
+    {
+        SetParIter {
+            inner: self.inner.as_slice().par_iter(),
+        }
+    }
+}
+
+impl<T: Send> IntoParallelIterator for VecSet<T> {
+    type Iter = SetIntoParIter<T>;
+
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        SetIntoParIter {
+            inner: self.inner.into_par_iter(),
+        }
+    }
+}
+
+impl<'a, T: Sync, S: Storage<T>> IntoParallelIterator for &'a VecSet<T, S> {
+    type Iter = SetParIter<'a, T>;
+
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter()
+    }
+}
+
+impl<T, S> ParallelExtend<T> for VecSet<T, S>
+where
+    T: Eq + Send,
+    S: Storage<T>,
+{
+    fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+        let vec: Vec<_> = par_iter.into_par_iter().collect();
+        self.extend(vec);
+    }
+}
+
+impl<T, S> FromParallelIterator<T> for VecSet<T, S>
+where
+    T: Eq + Send,
+    S: Storage<T> + Default,
+{
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        let mut set = Self::default();
+        set.par_extend(par_iter);
+        set
+    }
+}
@@ -0,0 +1,50 @@
+use rayon::prelude::*;
+use std::vec::Vec;
+
+use crate::{map::VecMap, set::VecSet};
+
+#[test]
+fn test_map_par_iter() {
+    let mut map: VecMap<i32, i32> = (0..100).map(|i| (i, i)).collect();
+
+    assert_eq!(map.par_iter().map(|(_, v)| *v).sum::<i32>(), 4950);
+    map.par_values_mut().for_each(|v| *v *= 2);
+    map.par_iter_mut()
+        .filter(|(k, _)| **k % 2 == 0)
+        .for_each(|(_, v)| *v = 0);
+    assert_eq!((&map).into_par_iter().map(|(_, v)| *v).sum::<i32>(), 5000);
+
+    // Indexed iterators keep the order of the map
+    let keys: Vec<_> = map.par_iter().map(|(k, _)| *k).collect();
+    assert_eq!(keys, (0..100).collect::<Vec<_>>());
+    let entries: Vec<_> = map.into_par_iter().collect();
+    assert_eq!(entries[3], (3, 6));
+}
+
+#[test]
+fn test_map_collect_and_extend() {
+    let mut map: VecMap<i32, i32> = (0..10).into_par_iter().map(|i| (i % 5, i)).collect();
+    assert_eq!(map.len(), 5);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    assert_eq!(map[&0], 5);
+
+    map.par_extend((3..8).into_par_iter().map(|i| (i, -i)));
+    assert_eq!(map.len(), 8);
+    assert_eq!(map[&3], -3);
+}
+
+#[test]
+fn test_set_par_iter() {
+    let set: VecSet<i32> = (0..50).into_par_iter().map(|i| i % 10).collect();
+    assert_eq!(set.len(), 10);
+    assert_eq!(
+        set.par_iter().copied().collect::<Vec<_>>(),
+        (0..10).collect::<Vec<_>>()
+    );
+    assert_eq!((&set).into_par_iter().sum::<i32>(), 45);
+
+    let mut set = set;
+    set.par_extend([5, 10, 11].into_par_iter());
+    assert_eq!(set.len(), 12);
+    assert_eq!(set.into_par_iter().max(), Some(11));
+}
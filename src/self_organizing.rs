@@ -0,0 +1,285 @@
+use alloc::vec::Vec;
+
+use crate::{
+    equivalent::Equivalent,
+    map::{Iter, VecMap},
+    set::{self, VecSet},
+    storage::Storage,
+};
+
+#[cfg(test)]
+mod tests;
+
+///How a self-organizing collection reorders its entries after a successful lookup
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Heuristic {
+    ///Moves the found entry to the front, shifting the entries before it back by one
+    #[default]
+    MoveToFront,
+    ///Swaps the found entry with the one right before it, so hot entries move forward one step per lookup
+    Transpose,
+    ///Keeps the order untouched, only the cached index of the last hit is used
+    CacheOnly,
+}
+
+//Finds the first element matching `is_match`, checking the cached index first, then reorders the slice according to
+//the heuristic and caches the new index of the element
+fn lookup<T>(
+    slice: &mut [T],
+    heuristic: Heuristic,
+    last_hit: &mut Option<usize>,
+    mut is_match: impl FnMut(&T) -> bool,
+) -> Option<usize> {
+    //The cache can be stale after a removal, so the index is always checked against the key
+    let index = match *last_hit {
+        Some(i) if slice.get(i).is_some_and(&mut is_match) => i,
+        _ => slice.iter().position(is_match)?,
+    };
+    let index = match heuristic {
+        Heuristic::MoveToFront => {
+            slice[..=index].rotate_right(1);
+            0
+        }
+        Heuristic::Transpose if index > 0 => {
+            slice.swap(index - 1, index);
+            index - 1
+        }
+        Heuristic::Transpose | Heuristic::CacheOnly => index,
+    };
+    *last_hit = Some(index);
+    Some(index)
+}
+
+///A [`VecMap`] that reorders its entries on lookups so frequently accessed keys are found sooner.
+///
+///Lookups check the index of the last hit first, then fall back to the linear search of [`VecMap`], and move the
+///found entry forward according to the [`Heuristic`]. This pays off for skewed access patterns, where a few keys
+///account for most lookups.
+///
+///Because lookups reorder the entries, they take `&mut self`, and the iteration order is the order left by past
+///lookups rather than the insertion order. Use [`Heuristic::CacheOnly`] to keep the insertion order and only benefit
+///from the cached index.
+#[derive(Clone)]
+pub struct SelfOrganizingMap<K, V, S = Vec<(K, V)>> {
+    map: VecMap<K, V, S>,
+    heuristic: Heuristic,
+    last_hit: Option<usize>,
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug, S: Storage<(K, V)>> core::fmt::Debug
+    for SelfOrganizingMap<K, V, S>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SelfOrganizingMap")
+            .field("map", &self.map)
+            .field("heuristic", &self.heuristic)
+            .finish()
+    }
+}
+
+impl<K, V, S> SelfOrganizingMap<K, V, S>
+where
+    K: Eq,
+    S: Storage<(K, V)>,
+{
+    ///Wraps the map, its current order is kept until the first lookup
+    pub const fn new(map: VecMap<K, V, S>, heuristic: Heuristic) -> Self {
+        Self {
+            map,
+            heuristic,
+            last_hit: None,
+        }
+    }
+
+    pub const fn heuristic(&self) -> Heuristic {
+        self.heuristic
+    }
+
+    pub const fn as_map(&self) -> &VecMap<K, V, S> {
+        &self.map
+    }
+
+    ///Returns a mutable reference to the inner map, any changes to it are taken into account by the next lookup
+    pub fn as_map_mut(&mut self) -> &mut VecMap<K, V, S> {
+        &mut self.map
+    }
+
+    pub fn into_inner(self) -> VecMap<K, V, S> {
+        self.map
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    ///An iterator over the entries in their current order, which depends on the past lookups
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.map.iter()
+    }
+
+    ///Inserts the pair like [`VecMap::insert`], a new key is appended to the end
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.map.insert(k, v)
+    }
+
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.map.remove(k)
+    }
+
+    ///Returns the index of the key after moving it according to the heuristic
+    pub fn get_index_of<Q>(&mut self, k: &Q) -> Option<usize>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        lookup(
+            self.map.vec.as_mut_slice(),
+            self.heuristic,
+            &mut self.last_hit,
+            |(key, _)| k.equivalent(key),
+        )
+    }
+
+    pub fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let index = self.get_index_of(k)?;
+        Some(&self.map.vec.as_slice()[index].1)
+    }
+
+    pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let index = self.get_index_of(k)?;
+        Some(&mut self.map.vec.as_mut_slice()[index].1)
+    }
+
+    pub fn get_key_value<Q>(&mut self, k: &Q) -> Option<(&K, &V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        let index = self.get_index_of(k)?;
+        let (k, v) = &self.map.vec.as_slice()[index];
+        Some((k, v))
+    }
+
+    pub fn contains_key<Q>(&mut self, k: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.get_index_of(k).is_some()
+    }
+}
+
+///A [`VecSet`] that reorders its values on lookups so frequently accessed values are found sooner.
+///
+///It works like [`SelfOrganizingMap`], with the same caveat: lookups take `&mut self` and change the iteration order
+///unless the heuristic is [`Heuristic::CacheOnly`].
+#[derive(Clone)]
+pub struct SelfOrganizingSet<T, S = Vec<T>> {
+    set: VecSet<T, S>,
+    heuristic: Heuristic,
+    last_hit: Option<usize>,
+}
+
+impl<T: core::fmt::Debug + Eq, S: Storage<T>> core::fmt::Debug for SelfOrganizingSet<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SelfOrganizingSet")
+            .field("set", &self.set)
+            .field("heuristic", &self.heuristic)
+            .finish()
+    }
+}
+
+impl<T, S> SelfOrganizingSet<T, S>
+where
+    T: Eq,
+    S: Storage<T>,
+{
+    ///Wraps the set, its current order is kept until the first lookup
+    pub const fn new(set: VecSet<T, S>, heuristic: Heuristic) -> Self {
+        Self {
+            set,
+            heuristic,
+            last_hit: None,
+        }
+    }
+
+    pub const fn heuristic(&self) -> Heuristic {
+        self.heuristic
+    }
+
+    pub const fn as_set(&self) -> &VecSet<T, S> {
+        &self.set
+    }
+
+    ///Returns a mutable reference to the inner set, any changes to it are taken into account by the next lookup
+    pub fn as_set_mut(&mut self) -> &mut VecSet<T, S> {
+        &mut self.set
+    }
+
+    pub fn into_inner(self) -> VecSet<T, S> {
+        self.set
+    }
+
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
+    ///An iterator over the values in their current order, which depends on the past lookups
+    pub fn iter(&self) -> set::Iter<'_, T> {
+        self.set.iter()
+    }
+
+    ///Inserts the value like [`VecSet::insert`], a new value is appended to the end
+    pub fn insert(&mut self, value: T) -> bool {
+        self.set.insert(value)
+    }
+
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        self.set.remove(value)
+    }
+
+    ///Returns the index of the value after moving it according to the heuristic
+    pub fn get_index_of<Q>(&mut self, value: &Q) -> Option<usize>
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        lookup(
+            self.set.inner.as_mut_slice(),
+            self.heuristic,
+            &mut self.last_hit,
+            |v| value.equivalent(v),
+        )
+    }
+
+    pub fn get<Q>(&mut self, value: &Q) -> Option<&T>
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        let index = self.get_index_of(value)?;
+        Some(&self.set.inner.as_slice()[index])
+    }
+
+    pub fn contains<Q>(&mut self, value: &Q) -> bool
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        self.get_index_of(value).is_some()
+    }
+}
@@ -0,0 +1,94 @@
+use std::{string::String, vec::Vec};
+
+use crate::{map::VecMap, self_organizing::*, set::VecSet};
+
+fn map(heuristic: Heuristic) -> SelfOrganizingMap<&'static str, i32> {
+    SelfOrganizingMap::new(
+        VecMap::from([("a", 1), ("b", 2), ("c", 3), ("d", 4)]),
+        heuristic,
+    )
+}
+
+fn keys<V>(map: &SelfOrganizingMap<&'static str, V>) -> Vec<&'static str> {
+    map.iter().map(|(k, _)| *k).collect()
+}
+
+#[test]
+fn test_move_to_front() {
+    let mut map = map(Heuristic::MoveToFront);
+    assert_eq!(map.get("c"), Some(&3));
+    assert_eq!(keys(&map), vec!["c", "a", "b", "d"]);
+
+    *map.get_mut("d").unwrap() += 10;
+    assert_eq!(keys(&map), vec!["d", "c", "a", "b"]);
+    assert_eq!(map.get_key_value("d"), Some((&"d", &14)));
+    assert_eq!(map.get_index_of("d"), Some(0));
+
+    assert_eq!(map.get("e"), None);
+    assert_eq!(keys(&map), vec!["d", "c", "a", "b"]);
+}
+
+#[test]
+fn test_transpose() {
+    let mut map = map(Heuristic::Transpose);
+    assert_eq!(map.get_index_of("d"), Some(2));
+    assert_eq!(keys(&map), vec!["a", "b", "d", "c"]);
+    assert!(map.contains_key("d"));
+    assert!(map.contains_key("d"));
+    assert_eq!(keys(&map), vec!["d", "a", "b", "c"]);
+
+    // The front entry stays in place
+    assert_eq!(map.get("d"), Some(&4));
+    assert_eq!(keys(&map), vec!["d", "a", "b", "c"]);
+}
+
+#[test]
+fn test_cache_only() {
+    let mut map = map(Heuristic::CacheOnly);
+    assert_eq!(map.get("c"), Some(&3));
+    assert_eq!(map.get("c"), Some(&3));
+    assert_eq!(keys(&map), vec!["a", "b", "c", "d"]);
+
+    // A removal leaves the cached index pointing at another key
+    assert_eq!(map.remove("a"), Some(1));
+    assert_eq!(map.get("c"), Some(&3));
+    assert_eq!(map.get_index_of("c"), Some(1));
+
+    assert_eq!(map.insert("e", 5), None);
+    assert_eq!(map.len(), 4);
+    map.as_map_mut().clear();
+    assert_eq!(map.get("c"), None);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_borrowed_lookup() {
+    let mut map = SelfOrganizingMap::new(
+        VecMap::from([(String::from("x"), 1), (String::from("y"), 2)]),
+        Heuristic::default(),
+    );
+    assert_eq!(map.get("y"), Some(&2));
+    assert_eq!(map.as_map().first(), Some((&String::from("y"), &2)));
+    assert_eq!(map.into_inner().len(), 2);
+}
+
+#[test]
+fn test_set() {
+    let mut set = SelfOrganizingSet::new(VecSet::from([1, 2, 3, 4]), Heuristic::MoveToFront);
+    assert!(set.contains(&3));
+    assert!(!set.contains(&5));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![3, 1, 2, 4]);
+    assert_eq!(set.get(&4), Some(&4));
+    assert_eq!(set.as_set().iter().next(), Some(&4));
+
+    let mut set = SelfOrganizingSet::new(set.into_inner(), Heuristic::Transpose);
+    assert_eq!(set.get_index_of(&2), Some(2));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    assert!(set.remove(&4));
+    assert!(set.insert(5));
+    assert!(!set.insert(5));
+    assert_eq!(set.len(), 4);
+    assert!(!set.is_empty());
+    set.as_set_mut().clear();
+    assert!(!set.contains(&3));
+}
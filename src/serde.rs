@@ -0,0 +1,257 @@
+//!`Serialize` and `Deserialize` impls for [`VecMap`] and [`VecSet`], along with helpers for choosing how duplicate
+//!keys are handled while deserializing.
+//!
+//!Both are serialized in storage order, [`VecMap`] as a map and [`VecSet`] as a sequence. Deserializing keeps the
+//!on-wire order, and by default a duplicate key overwrites the earlier value in place, like
+//![`VecMap::insert`]. Use one of the helpers with `#[serde(deserialize_with = "...")]` to pick another policy:
+//!
+//!```
+//!# use vec_key_value_pair::map::VecMap;
+//!#[derive(serde::Deserialize)]
+//!struct Config {
+//!    #[serde(deserialize_with = "vec_key_value_pair::serde::deny_duplicates")]
+//!    aliases: VecMap<String, String>,
+//!}
+//!```
+
+use core::{fmt, marker::PhantomData};
+
+use ::serde::{
+    de::{Error, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{map::VecMap, set::VecSet, storage::Storage};
+
+#[cfg(test)]
+mod tests;
+
+///How duplicate keys, or values for a set, are handled while deserializing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    ///The first occurrence is kept, later ones are ignored
+    FirstWins,
+    ///Later occurrences overwrite the value of the first one, which keeps its position
+    LastWins,
+    ///Deserialization fails on the first duplicate
+    Error,
+}
+
+///Collections that can be deserialized with a given [`DuplicatePolicy`]
+pub trait DeserializeWithPolicy<'de>: Sized {
+    fn deserialize_with_policy<D>(
+        deserializer: D,
+        policy: DuplicatePolicy,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+///Deserializes the collection keeping the first occurrence of every duplicate key
+pub fn first_wins<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeWithPolicy<'de>,
+{
+    T::deserialize_with_policy(deserializer, DuplicatePolicy::FirstWins)
+}
+
+///Deserializes the collection letting the last occurrence of every duplicate key win, this is the default behavior
+pub fn last_wins<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeWithPolicy<'de>,
+{
+    T::deserialize_with_policy(deserializer, DuplicatePolicy::LastWins)
+}
+
+///Deserializes the collection, failing if it contains any duplicate keys
+pub fn deny_duplicates<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeWithPolicy<'de>,
+{
+    T::deserialize_with_policy(deserializer, DuplicatePolicy::Error)
+}
+
+///Caps the capacity reserved from an untrusted size hint
+fn cautious_size_hint(hint: Option<usize>) -> usize {
+    hint.unwrap_or(0).min(4096)
+}
+
+///The error returned when the input holds more elements than a fixed capacity storage can fit
+fn capacity_error<E: Error>(capacity: usize) -> E {
+    E::custom(format_args!(
+        "too many elements, the storage can hold at most {capacity}"
+    ))
+}
+
+impl<K, V, S> Serialize for VecMap<K, V, S>
+where
+    K: Eq + Serialize,
+    V: Serialize,
+    S: Storage<(K, V)>,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_map(self)
+    }
+}
+
+struct MapVisitor<K, V, S> {
+    policy: DuplicatePolicy,
+    _marker: PhantomData<VecMap<K, V, S>>,
+}
+
+impl<'de, K, V, S> Visitor<'de> for MapVisitor<K, V, S>
+where
+    K: Eq + Deserialize<'de>,
+    V: Deserialize<'de>,
+    S: Storage<(K, V)> + Default,
+{
+    type Value = VecMap<K, V, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut map = VecMap::default();
+        map.reserve(cautious_size_hint(access.size_hint()));
+
+        while let Some((k, v)) = access.next_entry()? {
+            match self.policy {
+                DuplicatePolicy::LastWins => {
+                    map.try_insert(k, v)
+                        .map_err(|_| capacity_error(map.capacity()))?;
+                }
+                DuplicatePolicy::FirstWins => {
+                    if !map.contains_key(&k) {
+                        map.try_insert(k, v)
+                            .map_err(|_| capacity_error(map.capacity()))?;
+                    }
+                }
+                DuplicatePolicy::Error => {
+                    if map.contains_key(&k) {
+                        return Err(A::Error::custom("duplicate key in map"));
+                    }
+                    map.try_insert(k, v)
+                        .map_err(|_| capacity_error(map.capacity()))?;
+                }
+            }
+        }
+        Ok(map)
+    }
+}
+
+impl<'de, K, V, S> DeserializeWithPolicy<'de> for VecMap<K, V, S>
+where
+    K: Eq + Deserialize<'de>,
+    V: Deserialize<'de>,
+    S: Storage<(K, V)> + Default,
+{
+    fn deserialize_with_policy<D>(
+        deserializer: D,
+        policy: DuplicatePolicy,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MapVisitor {
+            policy,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'de, K, V, S> Deserialize<'de> for VecMap<K, V, S>
+where
+    K: Eq + Deserialize<'de>,
+    V: Deserialize<'de>,
+    S: Storage<(K, V)> + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        last_wins(deserializer)
+    }
+}
+
+impl<T, S> Serialize for VecSet<T, S>
+where
+    T: Eq + Serialize,
+    S: Storage<T>,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+struct SetVisitor<T, S> {
+    policy: DuplicatePolicy,
+    _marker: PhantomData<VecSet<T, S>>,
+}
+
+impl<'de, T, S> Visitor<'de> for SetVisitor<T, S>
+where
+    T: Eq + Deserialize<'de>,
+    S: Storage<T> + Default,
+{
+    type Value = VecSet<T, S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut set = VecSet::default();
+        set.reserve(cautious_size_hint(access.size_hint()));
+
+        while let Some(value) = access.next_element()? {
+            match self.policy {
+                DuplicatePolicy::LastWins if set.contains(&value) => {
+                    set.replace(value);
+                }
+                DuplicatePolicy::LastWins | DuplicatePolicy::FirstWins => {
+                    set.try_insert(value)
+                        .map_err(|_| capacity_error(set.capacity()))?;
+                }
+                DuplicatePolicy::Error => {
+                    let inserted = set
+                        .try_insert(value)
+                        .map_err(|_| capacity_error(set.capacity()))?;
+                    if !inserted {
+                        return Err(A::Error::custom("duplicate value in set"));
+                    }
+                }
+            }
+        }
+        Ok(set)
+    }
+}
+
+impl<'de, T, S> DeserializeWithPolicy<'de> for VecSet<T, S>
+where
+    T: Eq + Deserialize<'de>,
+    S: Storage<T> + Default,
+{
+    fn deserialize_with_policy<D>(
+        deserializer: D,
+        policy: DuplicatePolicy,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(SetVisitor {
+            policy,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'de, T, S> Deserialize<'de> for VecSet<T, S>
+where
+    T: Eq + Deserialize<'de>,
+    S: Storage<T> + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        last_wins(deserializer)
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(feature = "arrayvec")]
+use arrayvec::ArrayVec;
+use serde::Deserialize;
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+use crate::{map::VecMap, set::VecSet};
+
+#[test]
+fn test_map_round_trip_keeps_order() {
+    let map = VecMap::from([('c', 3), ('a', 1), ('b', 2)]);
+    assert_tokens(
+        &map,
+        &[
+            Token::Map { len: Some(3) },
+            Token::Char('c'),
+            Token::I32(3),
+            Token::Char('a'),
+            Token::I32(1),
+            Token::Char('b'),
+            Token::I32(2),
+            Token::MapEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_set_round_trip_keeps_order() {
+    let set = VecSet::from([3, 1, 2]);
+    assert_tokens(
+        &set,
+        &[
+            Token::Seq { len: Some(3) },
+            Token::I32(3),
+            Token::I32(1),
+            Token::I32(2),
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Policies {
+    #[serde(deserialize_with = "crate::serde::first_wins")]
+    first: VecMap<char, i32>,
+    last: VecMap<char, i32>,
+    #[serde(deserialize_with = "crate::serde::deny_duplicates")]
+    unique: VecSet<i32>,
+}
+
+fn policy_tokens(unique: &[i32]) -> std::vec::Vec<Token> {
+    let mut tokens = std::vec![
+        Token::Struct {
+            name: "Policies",
+            len: 3,
+        },
+        Token::Str("first"),
+        Token::Map { len: Some(3) },
+        Token::Char('a'),
+        Token::I32(1),
+        Token::Char('b'),
+        Token::I32(2),
+        Token::Char('a'),
+        Token::I32(3),
+        Token::MapEnd,
+        Token::Str("last"),
+        Token::Map { len: Some(3) },
+        Token::Char('a'),
+        Token::I32(1),
+        Token::Char('b'),
+        Token::I32(2),
+        Token::Char('a'),
+        Token::I32(3),
+        Token::MapEnd,
+        Token::Str("unique"),
+        Token::Seq {
+            len: Some(unique.len()),
+        },
+    ];
+    tokens.extend(unique.iter().map(|&i| Token::I32(i)));
+    tokens.extend([Token::SeqEnd, Token::StructEnd]);
+    tokens
+}
+
+#[test]
+fn test_duplicate_policies() {
+    let expected = Policies {
+        first: VecMap::from([('a', 1), ('b', 2)]),
+        last: VecMap::from([('a', 3), ('b', 2)]),
+        unique: VecSet::from([1, 2]),
+    };
+    assert_de_tokens(&expected, &policy_tokens(&[1, 2]));
+    let tokens = policy_tokens(&[1, 2, 1]);
+    assert_de_tokens_error::<Policies>(&tokens[..tokens.len() - 2], "duplicate value in set");
+
+    // The first occurrence keeps its position with both policies
+    assert_eq!(
+        expected.last.keys().copied().collect::<std::vec::Vec<_>>(),
+        ['a', 'b']
+    );
+}
+
+#[test]
+fn test_deny_duplicate_keys() {
+    #[derive(Deserialize, Debug)]
+    struct Strict {
+        #[serde(deserialize_with = "crate::serde::deny_duplicates")]
+        _map: VecMap<char, i32>,
+    }
+
+    assert_de_tokens_error::<Strict>(
+        &[
+            Token::Struct {
+                name: "Strict",
+                len: 1,
+            },
+            Token::Str("_map"),
+            Token::Map { len: Some(2) },
+            Token::Char('a'),
+            Token::I32(1),
+            Token::Char('a'),
+            Token::I32(2),
+        ],
+        "duplicate key in map",
+    );
+}
+
+#[test]
+#[cfg(feature = "arrayvec")]
+fn test_fixed_capacity_overflow() {
+    type Map = VecMap<char, i32, ArrayVec<(char, i32), 1>>;
+    type Set = VecSet<i32, ArrayVec<i32, 1>>;
+
+    // A duplicate doesn't need any space
+    let mut expected = Map::default();
+    expected.insert('a', 2);
+    assert_de_tokens(
+        &expected,
+        &[
+            Token::Map { len: Some(2) },
+            Token::Char('a'),
+            Token::I32(1),
+            Token::Char('a'),
+            Token::I32(2),
+            Token::MapEnd,
+        ],
+    );
+    assert_de_tokens_error::<Map>(
+        &[
+            Token::Map { len: Some(2) },
+            Token::Char('a'),
+            Token::I32(1),
+            Token::Char('b'),
+            Token::I32(2),
+        ],
+        "too many elements, the storage can hold at most 1",
+    );
+    assert_de_tokens_error::<Set>(
+        &[Token::Seq { len: Some(2) }, Token::I32(1), Token::I32(2)],
+        "too many elements, the storage can hold at most 1",
+    );
+}
@@ -1,21 +1,46 @@
-use std::{
-    borrow::Borrow,
-    collections::TryReserveError,
+use alloc::{
+    collections::{BTreeSet, TryReserveError},
+    vec::Vec,
+};
+use core::{
     fmt::Debug,
+    hash::{Hash, Hasher},
     iter::{Chain, FusedIterator},
+    marker::PhantomData,
     ops::{BitAnd, BitOr, BitXor, Sub},
-    vec,
+};
+
+#[cfg(feature = "std")]
+use std::{collections::HashSet, hash::BuildHasher};
+
+use crate::{
+    equivalent::Equivalent,
+    hash::hash_unordered,
+    storage::{CapacityError, Extractor, Storage},
 };
 
 #[cfg(test)]
+#[allow(dead_code, clippy::never_loop)]
 mod tests;
 
-pub struct VecSet<T> {
-    inner: Vec<T>,
+///A drop in replacement for [`std::collections::HashSet`] for extensive documentation and examples, see the original data
+///structure.
+///
+///Unlike [`std::collections::HashSet`], `VecSet` has a defined iteration order: values are kept in the order they
+///were first inserted. Replacing a value keeps it in place, and removing a value shifts the following values down by
+///one, preserving their relative order.
+///
+///There is deliberately no `get_index_mut`: mutating a value in place could make it equal to another value of the
+///set and break uniqueness. Remove the value and insert the changed one instead.
+///
+///The values are stored in a [`Vec`] by default, any other [`Storage`] can be used instead.
+pub struct VecSet<T, S = Vec<T>> {
+    pub(crate) inner: S,
+    pub(crate) _marker: PhantomData<T>,
 }
 
 pub struct Iter<'a, T> {
-    inner: core::slice::Iter<'a, T>,
+    pub(crate) inner: core::slice::Iter<'a, T>,
 }
 
 impl<K> Clone for Iter<'_, K> {
@@ -27,7 +52,7 @@ impl<K> Clone for Iter<'_, K> {
 }
 
 impl<K: Debug> Debug for Iter<'_, K> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_list().entries(self.clone()).finish()
     }
 }
@@ -48,19 +73,26 @@ impl<'a, K> Iterator for Iter<'a, K> {
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
 }
 
-pub struct Drain<'a, T> {
-    inner: std::vec::Drain<'a, T>,
+pub struct Drain<'a, T, S: Storage<T> + 'a = Vec<T>> {
+    inner: S::Drain<'a>,
 }
 
-impl<K: Debug> Debug for Drain<'_, K> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<K, S: Storage<K>> Debug for Drain<'_, K, S>
+where
+    for<'a> S::Drain<'a>: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         Debug::fmt(&self.inner, f)
     }
 }
 
-impl<K> ExactSizeIterator for Drain<'_, K> {
+impl<K, S: Storage<K>> ExactSizeIterator for Drain<'_, K, S> {
     fn len(&self) -> usize {
         let (lower, upper) = self.inner.size_hint();
         // Note: This assertion is overly defensive, but it checks the invariant
@@ -72,9 +104,9 @@ impl<K> ExactSizeIterator for Drain<'_, K> {
     }
 }
 
-impl<K> FusedIterator for Drain<'_, K> {}
+impl<K, S: Storage<K>> FusedIterator for Drain<'_, K, S> {}
 
-impl<'a, K> Iterator for Drain<'a, K> {
+impl<K, S: Storage<K>> Iterator for Drain<'_, K, S> {
     type Item = K;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -82,9 +114,53 @@ impl<'a, K> Iterator for Drain<'a, K> {
     }
 }
 
+///A lazy iterator removing the values matching a predicate, created by [`VecSet::extract_if`]
+pub struct ExtractIf<'a, T, F, S = Vec<T>>
+where
+    F: FnMut(&T) -> bool,
+    S: Storage<T>,
+{
+    inner: Extractor<'a, T, S>,
+    pred: F,
+}
+
+impl<T, F, S> Debug for ExtractIf<'_, T, F, S>
+where
+    F: FnMut(&T) -> bool,
+    S: Storage<T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ExtractIf").finish_non_exhaustive()
+    }
+}
+
+impl<T, F, S> FusedIterator for ExtractIf<'_, T, F, S>
+where
+    F: FnMut(&T) -> bool,
+    S: Storage<T>,
+{
+}
+
+impl<T, F, S> Iterator for ExtractIf<'_, T, F, S>
+where
+    F: FnMut(&T) -> bool,
+    S: Storage<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pred = &mut self.pred;
+        self.inner.next(|v| pred(v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.inner.remaining()))
+    }
+}
+
 pub struct Difference<'a, T> {
     iter: Iter<'a, T>,
-    other: &'a VecSet<T>,
+    other: &'a [T],
 }
 
 impl<T> Clone for Difference<'_, T> {
@@ -100,7 +176,7 @@ impl<T: Debug> Debug for Difference<'_, T>
 where
     T: Eq,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_list().entries(self.clone()).finish()
     }
 }
@@ -128,6 +204,7 @@ where
 {
     type Item = &'a T;
 
+    #[allow(clippy::while_let_on_iterator)]
     fn next(&mut self) -> Option<Self::Item> {
         while let Some(n) = self.iter.next() {
             if !self.other.contains(n) {
@@ -154,7 +231,7 @@ impl<T: Debug> Debug for SymmetricDifference<'_, T>
 where
     T: Eq,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_list().entries(self.clone()).finish()
     }
 }
@@ -174,7 +251,7 @@ where
 
 pub struct Intersection<'a, T> {
     iter: Iter<'a, T>,
-    other: &'a VecSet<T>,
+    other: &'a [T],
 }
 
 impl<T> Clone for Intersection<'_, T> {
@@ -190,7 +267,7 @@ impl<T: Debug> Debug for Intersection<'_, T>
 where
     T: Eq,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_list().entries(self.clone()).finish()
     }
 }
@@ -203,6 +280,7 @@ where
 {
     type Item = &'a T;
 
+    #[allow(clippy::while_let_on_iterator)]
     fn next(&mut self) -> Option<Self::Item> {
         while let Some(n) = self.iter.next() {
             if self.other.contains(n) {
@@ -229,7 +307,7 @@ impl<T: Debug> Debug for Union<'_, T>
 where
     T: Eq,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_list().entries(self.clone()).finish()
     }
 }
@@ -247,25 +325,29 @@ where
     }
 }
 
-pub struct IntoIter<T> {
-    inner: vec::IntoIter<T>,
+pub struct IntoIter<T, S: Storage<T> = Vec<T>> {
+    inner: S::IntoIter,
+    _marker: PhantomData<T>,
 }
 
-impl<T: Debug> Debug for IntoIter<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T, S: Storage<T>> Debug for IntoIter<T, S>
+where
+    S::IntoIter: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         Debug::fmt(&self.inner, f)
     }
 }
 
-impl<T> ExactSizeIterator for IntoIter<T> {
+impl<T, S: Storage<T>> ExactSizeIterator for IntoIter<T, S> {
     fn len(&self) -> usize {
         self.inner.len()
     }
 }
 
-impl<T> FusedIterator for IntoIter<T> {}
+impl<T, S: Storage<T>> FusedIterator for IntoIter<T, S> {}
 
-impl<'a, T> Iterator for IntoIter<T> {
+impl<T, S: Storage<T>> Iterator for IntoIter<T, S> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -275,12 +357,39 @@ impl<'a, T> Iterator for IntoIter<T> {
 
 impl<T> VecSet<T> {
     pub fn new() -> Self {
-        Self { inner: Vec::new() }
+        Self {
+            inner: Vec::new(),
+            _marker: PhantomData,
+        }
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             inner: Vec::with_capacity(capacity),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(additional)
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit();
+    }
+
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.inner.shrink_to(min_capacity);
+    }
+}
+
+impl<T, S: Storage<T>> VecSet<T, S> {
+    ///Creates an empty `VecSet` backed by the given storage, any elements already in it are removed
+    pub fn with_storage(mut storage: S) -> Self {
+        storage.clear();
+        Self {
+            inner: storage,
+            _marker: PhantomData,
         }
     }
 
@@ -290,29 +399,43 @@ impl<T> VecSet<T> {
 
     pub fn iter(&self) -> Iter<'_, T> {
         Iter {
-            inner: self.inner.iter(),
+            inner: self.inner.as_slice().iter(),
         }
     }
 
     pub fn len(&self) -> usize {
-        self.inner.len()
+        self.inner.as_slice().len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.inner.is_empty()
+        self.inner.as_slice().is_empty()
     }
 
-    pub fn drain(&mut self) -> Drain<'_, T> {
+    pub fn drain(&mut self) -> Drain<'_, T, S> {
         Drain {
-            inner: self.inner.drain(..),
+            inner: self.inner.drain(),
+        }
+    }
+
+    ///Creates an iterator that removes and yields all values for which `pred` returns `true`, in order.
+    ///
+    ///Values are only visited as the iterator is advanced, if it's dropped before being exhausted, all values that
+    ///weren't visited yet are kept in the set. The order of the remaining values is preserved
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F, S>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        ExtractIf {
+            inner: Extractor::new(&mut self.inner),
+            pred,
         }
     }
 
-    pub fn retain<F>(&mut self, f: F)
+    pub fn retain<F>(&mut self, mut f: F)
     where
         F: FnMut(&T) -> bool,
     {
-        self.inner.retain(f);
+        self.inner.retain_mut(|v| f(v));
     }
 
     pub fn clear(&mut self) {
@@ -323,51 +446,101 @@ impl<T> VecSet<T> {
         self.inner.reserve(additional);
     }
 
-    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
-        self.inner.try_reserve(additional)
+    ///Returns the value at the given index, or `None` if the index is out of bounds
+    pub fn get_index(&self, index: usize) -> Option<&T> {
+        self.inner.as_slice().get(index)
     }
 
-    pub fn shrink_to_fit(&mut self) {
-        self.inner.shrink_to_fit();
+    ///Swaps the positions of two values in the set
+    ///
+    ///Panics if either `a` or `b` are out of bounds
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        self.inner.as_mut_slice().swap(a, b);
     }
 
-    pub fn shrink_to(&mut self, min_capacity: usize) {
-        self.inner.shrink_to(min_capacity);
+    ///Moves the value at index `from` to index `to`, shifting all values in between to fill the gap
+    ///
+    ///Panics if either `from` or `to` are out of bounds
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        if from < to {
+            self.inner.as_mut_slice()[from..=to].rotate_left(1);
+        } else {
+            self.inner.as_mut_slice()[to..=from].rotate_right(1);
+        }
+    }
+
+    ///Returns the first value in the set
+    pub fn first(&self) -> Option<&T> {
+        self.inner.as_slice().first()
+    }
+
+    ///Returns the last value in the set
+    pub fn last(&self) -> Option<&T> {
+        self.inner.as_slice().last()
+    }
+
+    ///Removes and returns the last value in the set
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    ///Removes the value at the given index by swapping it with the last value, this does not preserve the order of
+    ///the set, but is O(1)
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(self.inner.swap_remove(index))
+    }
+
+    ///Removes the value at the given index, shifting all following values down by one
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(self.inner.remove(index))
     }
 }
 
-impl<T> VecSet<T>
+impl<T, S> VecSet<T, S>
 where
     T: Eq,
+    S: Storage<T>,
 {
-    pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, T> {
+    pub fn difference<'a, S2: Storage<T>>(&'a self, other: &'a VecSet<T, S2>) -> Difference<'a, T> {
         Difference {
             iter: self.iter(),
-            other,
+            other: other.inner.as_slice(),
         }
     }
 
-    pub fn symmetric_difference<'a>(&'a self, other: &'a VecSet<T>) -> SymmetricDifference<'a, T> {
+    pub fn symmetric_difference<'a, S2: Storage<T>>(
+        &'a self,
+        other: &'a VecSet<T, S2>,
+    ) -> SymmetricDifference<'a, T> {
         SymmetricDifference {
             iter: self.difference(other).chain(other.difference(self)),
         }
     }
 
-    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, T> {
+    pub fn intersection<'a, S2: Storage<T>>(
+        &'a self,
+        other: &'a VecSet<T, S2>,
+    ) -> Intersection<'a, T> {
         if self.len() <= other.len() {
             Intersection {
                 iter: self.iter(),
-                other,
+                other: other.inner.as_slice(),
             }
         } else {
             Intersection {
                 iter: other.iter(),
-                other: self,
+                other: self.inner.as_slice(),
             }
         }
     }
 
-    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, T> {
+    pub fn union<'a, S2: Storage<T>>(&'a self, other: &'a VecSet<T, S2>) -> Union<'a, T> {
         if self.len() >= other.len() {
             Union {
                 iter: self.iter().chain(other.difference(self)),
@@ -381,32 +554,42 @@ where
 
     pub fn contains<Q>(&self, value: &Q) -> bool
     where
-        T: Borrow<Q>,
-        Q: Eq + ?Sized,
+        Q: Equivalent<T> + ?Sized,
     {
-        for i in &self.inner {
-            if i.borrow() == value {
+        for i in self.inner.as_slice() {
+            if value.equivalent(i) {
                 return true;
             }
         }
         false
     }
 
+    #[allow(clippy::manual_find)]
     pub fn get<Q>(&self, value: &Q) -> Option<&T>
     where
-        T: Borrow<Q>,
-        Q: Eq + ?Sized,
+        Q: Equivalent<T> + ?Sized,
     {
-        for i in &self.inner {
-            if i.borrow() == value {
+        for i in self.inner.as_slice() {
+            if value.equivalent(i) {
                 return Some(i);
             }
         }
         None
     }
 
-    pub fn is_disjoint(&self, other: &Self) -> bool {
-        for i in &self.inner {
+    ///Returns the index of the given value, if it's present in the set
+    pub fn get_index_of<Q>(&self, value: &Q) -> Option<usize>
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        self.inner
+            .as_slice()
+            .iter()
+            .position(|i| value.equivalent(i))
+    }
+
+    pub fn is_disjoint<S2: Storage<T>>(&self, other: &VecSet<T, S2>) -> bool {
+        for i in self.inner.as_slice() {
             if other.contains(i) {
                 return false;
             }
@@ -414,8 +597,8 @@ where
         true
     }
 
-    pub fn is_subset(&self, other: &Self) -> bool {
-        for i in &self.inner {
+    pub fn is_subset<S2: Storage<T>>(&self, other: &VecSet<T, S2>) -> bool {
+        for i in self.inner.as_slice() {
             if !other.contains(i) {
                 return false;
             }
@@ -423,7 +606,7 @@ where
         true
     }
 
-    pub fn is_superset(&self, other: &Self) -> bool {
+    pub fn is_superset<S2: Storage<T>>(&self, other: &VecSet<T, S2>) -> bool {
         other.is_subset(self)
     }
 
@@ -436,18 +619,31 @@ where
         true
     }
 
+    ///Adds a value to the set like [`VecSet::insert`]. If the value is new and the storage is full, it's returned in
+    ///a [`CapacityError`].
+    ///
+    ///Only storages with a fixed capacity, like `ArrayVec`, can fail, [`VecSet::insert`] panics instead
+    pub fn try_insert(&mut self, value: T) -> Result<bool, CapacityError<T>> {
+        if self.contains(&value) {
+            return Ok(false);
+        }
+        self.inner.try_push(value)?;
+
+        Ok(true)
+    }
+
+    ///Adds a value to the set, replacing the existing value, if any, that is equal to the given one. The replaced
+    ///value keeps its position in the set. Returns the replaced value.
     pub fn replace(&mut self, value: T) -> Option<T> {
         let mut r_index = None;
-        for (index, v) in self.inner.iter().enumerate() {
+        for (index, v) in self.inner.as_slice().iter().enumerate() {
             if v == &value {
                 r_index = Some(index);
                 break;
             }
         }
         if let Some(i) = r_index {
-            let old = self.inner.remove(i);
-            self.inner.push(value);
-            return Some(old);
+            return Some(core::mem::replace(&mut self.inner.as_mut_slice()[i], value));
         }
 
         self.inner.push(value);
@@ -455,95 +651,135 @@ where
         None
     }
 
+    ///Removes a value from the set. All following values are shifted down by one, so the order of the remaining
+    ///values is preserved, see [`VecSet::shift_remove`]
     pub fn remove<Q>(&mut self, value: &Q) -> bool
     where
-        T: Borrow<Q>,
-        Q: Eq + ?Sized,
+        Q: Equivalent<T> + ?Sized,
     {
-        let mut r_index = None;
-        for (index, v) in self.inner.iter().enumerate() {
-            if v.borrow() == value {
-                r_index = Some(index);
-                break;
-            }
-        }
+        self.shift_remove(value)
+    }
 
-        if let Some(i) = r_index {
-            _ = self.inner.remove(i);
-            return true;
-        }
+    ///Removes a value from the set. The value is replaced by the last value of the set, this does not preserve the
+    ///order of the set, but is O(1) once the value is found
+    pub fn swap_remove<Q>(&mut self, value: &Q) -> bool
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        self.swap_take(value).is_some()
+    }
 
-        false
+    ///Removes a value from the set. All following values are shifted down by one, so the order of the remaining
+    ///values is preserved
+    pub fn shift_remove<Q>(&mut self, value: &Q) -> bool
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        self.shift_take(value).is_some()
+    }
+
+    ///Removes and returns the value in the set equal to the given one. All following values are shifted down by one,
+    ///so the order of the remaining values is preserved, see [`VecSet::shift_take`]
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        self.shift_take(value)
+    }
+
+    ///Removes and returns the value in the set equal to the given one, replacing it with the last value of the set
+    pub fn swap_take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        let i = self.get_index_of(value)?;
+        Some(self.inner.swap_remove(i))
+    }
+
+    ///Removes and returns the value in the set equal to the given one, shifting all following values down by one
+    pub fn shift_take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        Q: Equivalent<T> + ?Sized,
+    {
+        let i = self.get_index_of(value)?;
+        Some(self.inner.remove(i))
     }
 }
 
-impl<T> BitAnd<&VecSet<T>> for &VecSet<T>
+impl<T, S> BitAnd<&VecSet<T, S>> for &VecSet<T, S>
 where
     T: Eq + Clone,
+    S: Storage<T> + Default,
 {
-    type Output = VecSet<T>;
+    type Output = VecSet<T, S>;
 
-    fn bitand(self, rhs: &VecSet<T>) -> Self::Output {
+    fn bitand(self, rhs: &VecSet<T, S>) -> Self::Output {
         self.intersection(rhs).cloned().collect()
     }
 }
 
-impl<T> BitOr<&VecSet<T>> for &VecSet<T>
+impl<T, S> BitOr<&VecSet<T, S>> for &VecSet<T, S>
 where
     T: Eq + Clone,
+    S: Storage<T> + Default,
 {
-    type Output = VecSet<T>;
+    type Output = VecSet<T, S>;
 
-    fn bitor(self, rhs: &VecSet<T>) -> Self::Output {
+    fn bitor(self, rhs: &VecSet<T, S>) -> Self::Output {
         self.union(rhs).cloned().collect()
     }
 }
 
-impl<T> BitXor<&VecSet<T>> for &VecSet<T>
+impl<T, S> BitXor<&VecSet<T, S>> for &VecSet<T, S>
 where
     T: Eq + Clone,
+    S: Storage<T> + Default,
 {
-    type Output = VecSet<T>;
+    type Output = VecSet<T, S>;
 
-    fn bitxor(self, rhs: &VecSet<T>) -> Self::Output {
+    fn bitxor(self, rhs: &VecSet<T, S>) -> Self::Output {
         self.symmetric_difference(rhs).cloned().collect()
     }
 }
 
-impl<T> Clone for VecSet<T>
+impl<T, S> Clone for VecSet<T, S>
 where
-    T: Clone,
+    S: Clone,
 {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
+            _marker: PhantomData,
         }
     }
 }
 
-impl<T> Debug for VecSet<T>
+impl<T, S> Debug for VecSet<T, S>
 where
     T: Debug + Eq,
+    S: Storage<T>,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_set().entries(self).finish()
     }
 }
 
-impl<T> Default for VecSet<T>
+impl<T, S> Default for VecSet<T, S>
 where
-    T: Default,
+    S: Default,
 {
     fn default() -> Self {
         Self {
             inner: Default::default(),
+            _marker: PhantomData,
         }
     }
 }
 
-impl<'a, T> Extend<&'a T> for VecSet<T>
+impl<'a, T, S> Extend<&'a T> for VecSet<T, S>
 where
     T: 'a + Eq + Copy,
+    S: Storage<T>,
 {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
         for i in iter {
@@ -552,9 +788,10 @@ where
     }
 }
 
-impl<T> Extend<T> for VecSet<T>
+impl<T, S> Extend<T> for VecSet<T, S>
 where
     T: Eq,
+    S: Storage<T>,
 {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         for i in iter {
@@ -577,12 +814,77 @@ where
     }
 }
 
-impl<T> FromIterator<T> for VecSet<T>
+///Duplicate values are handled like [`VecSet::insert`], only the first occurrence is kept
+impl<T> From<Vec<T>> for VecSet<T>
 where
     T: Eq,
+{
+    fn from(value: Vec<T>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<T, S> From<VecSet<T, S>> for Vec<T>
+where
+    S: Storage<T>,
+{
+    fn from(value: VecSet<T, S>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+///The values are kept in the iteration order of the [`HashSet`]
+#[cfg(feature = "std")]
+impl<T, H> From<HashSet<T, H>> for VecSet<T> {
+    fn from(value: HashSet<T, H>) -> Self {
+        //The values are already unique, so there's no need to look them up
+        Self {
+            inner: value.into_iter().collect(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S, H> From<VecSet<T, S>> for HashSet<T, H>
+where
+    T: Eq + Hash,
+    S: Storage<T>,
+    H: BuildHasher + Default,
+{
+    fn from(value: VecSet<T, S>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+///The values are kept sorted
+impl<T> From<BTreeSet<T>> for VecSet<T> {
+    fn from(value: BTreeSet<T>) -> Self {
+        //The values are already unique, so there's no need to look them up
+        Self {
+            inner: value.into_iter().collect(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, S> From<VecSet<T, S>> for BTreeSet<T>
+where
+    T: Ord,
+    S: Storage<T>,
+{
+    fn from(value: VecSet<T, S>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<T, S> FromIterator<T> for VecSet<T, S>
+where
+    T: Eq,
+    S: Storage<T> + Default,
 {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut o = VecSet::new();
+        let mut o = VecSet::default();
         for i in iter {
             o.insert(i);
         }
@@ -591,48 +893,115 @@ where
     }
 }
 
-impl<'a, T> IntoIterator for &'a VecSet<T> {
+impl<'a, T, S: Storage<T>> IntoIterator for &'a VecSet<T, S> {
     type Item = &'a T;
 
     type IntoIter = Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
         Iter {
-            inner: self.inner.iter(),
+            inner: self.inner.as_slice().iter(),
         }
     }
 }
 
-impl<T> IntoIterator for VecSet<T> {
+impl<T, S: Storage<T>> IntoIterator for VecSet<T, S> {
     type Item = T;
 
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, S>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter {
             inner: self.inner.into_iter(),
+            _marker: PhantomData,
         }
     }
 }
 
-impl<T> PartialEq for VecSet<T>
+impl<T, S> PartialEq for VecSet<T, S>
 where
     T: Eq,
+    S: Storage<T>,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.is_subset(other) && self.is_superset(other)
+        self.len() == other.len() && self.is_subset(other)
     }
 }
 
-impl<T> Eq for VecSet<T> where T: Eq {}
+#[cfg(feature = "std")]
+impl<T, S, H> PartialEq<HashSet<T, H>> for VecSet<T, S>
+where
+    T: Eq + Hash,
+    S: Storage<T>,
+    H: BuildHasher,
+{
+    fn eq(&self, other: &HashSet<T, H>) -> bool {
+        self.len() == other.len() && self.iter().all(|v| other.contains(v))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S, H> PartialEq<VecSet<T, S>> for HashSet<T, H>
+where
+    T: Eq + Hash,
+    S: Storage<T>,
+    H: BuildHasher,
+{
+    fn eq(&self, other: &VecSet<T, S>) -> bool {
+        other == self
+    }
+}
+
+impl<T, S> PartialEq<BTreeSet<T>> for VecSet<T, S>
+where
+    T: Ord,
+    S: Storage<T>,
+{
+    fn eq(&self, other: &BTreeSet<T>) -> bool {
+        self.len() == other.len() && self.iter().all(|v| other.contains(v))
+    }
+}
+
+impl<T, S> PartialEq<VecSet<T, S>> for BTreeSet<T>
+where
+    T: Ord,
+    S: Storage<T>,
+{
+    fn eq(&self, other: &VecSet<T, S>) -> bool {
+        other == self
+    }
+}
+
+impl<T, S> Eq for VecSet<T, S>
+where
+    T: Eq,
+    S: Storage<T>,
+{
+}
+
+///The hash doesn't depend on the order of the values, so that it's consistent with [`PartialEq`].
+///
+///Like the [`Hash`] impl of [`crate::map::VecMap`], every value is first hashed with a fixed, unseeded hasher, so a
+///seeded `state` doesn't protect against HashDoS. Don't use sets built from untrusted input as keys of a hash based
+///collection.
+impl<T, S> Hash for VecSet<T, S>
+where
+    T: Hash,
+    S: Storage<T>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_unordered(self.inner.as_slice().iter(), state);
+    }
+}
 
-impl<T> Sub<&VecSet<T>> for &VecSet<T>
+impl<T, S> Sub<&VecSet<T, S>> for &VecSet<T, S>
 where
     T: Eq + Clone,
+    S: Storage<T> + Default,
 {
-    type Output = VecSet<T>;
+    type Output = VecSet<T, S>;
 
-    fn sub(self, rhs: &VecSet<T>) -> Self::Output {
+    fn sub(self, rhs: &VecSet<T, S>) -> Self::Output {
         self.difference(rhs).cloned().collect()
     }
 }
@@ -1,6 +1,14 @@
 use super::VecSet;
 
-use std::sync::Arc;
+use std::{
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+#[cfg(feature = "arrayvec")]
+use arrayvec::ArrayVec;
 
 #[test]
 fn test_zero_capacities() {
@@ -432,3 +440,298 @@ fn test_insert_does_not_overwrite_the_value() {
             must be the same as first value pointer we inserted"
     );
 }
+
+#[test]
+fn test_insertion_order() {
+    let mut set = VecSet::new();
+    set.insert(3);
+    set.insert(1);
+    set.insert(2);
+    assert!(!set.insert(3));
+
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [3, 1, 2]);
+}
+
+#[test]
+fn test_replace_keeps_position() {
+    #[derive(Debug)]
+    struct Foo(&'static str, i32);
+
+    impl PartialEq for Foo {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Foo {}
+
+    let mut s = VecSet::new();
+    s.insert(Foo("a", 1));
+    s.insert(Foo("b", 1));
+    s.insert(Foo("c", 1));
+
+    assert_eq!(s.replace(Foo("a", 2)), Some(Foo("a", 1)));
+    assert_eq!(
+        s.iter().map(|f| (f.0, f.1)).collect::<Vec<_>>(),
+        [("a", 2), ("b", 1), ("c", 1)]
+    );
+}
+
+#[test]
+fn test_remove_preserves_order() {
+    let mut set: VecSet<_> = (0..6).collect();
+
+    assert!(set.remove(&1));
+    assert!(set.remove(&3));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [0, 2, 4, 5]);
+
+    set.retain(|&k| k != 4);
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), [0, 2, 5]);
+}
+
+#[test]
+fn test_index_access() {
+    let mut set = VecSet::from([1, 2, 3]);
+
+    assert_eq!(set.get_index(0), Some(&1));
+    assert_eq!(set.get_index(3), None);
+    assert_eq!(set.get_index_of(&3), Some(2));
+    assert_eq!(set.get_index_of(&4), None);
+    assert_eq!(set.first(), Some(&1));
+    assert_eq!(set.last(), Some(&3));
+
+    set.swap_indices(0, 2);
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [3, 2, 1]);
+
+    set.move_index(0, 2);
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [2, 1, 3]);
+    set.move_index(2, 0);
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [3, 2, 1]);
+
+    assert_eq!(set.swap_remove_index(0), Some(3));
+    assert_eq!(set.swap_remove_index(5), None);
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 2]);
+
+    assert_eq!(set.pop(), Some(2));
+    assert_eq!(set.pop(), Some(1));
+    assert_eq!(set.pop(), None);
+    assert_eq!(set.first(), None);
+}
+
+#[test]
+fn test_swap_and_shift_remove() {
+    let mut set: VecSet<_> = (0..6).collect();
+
+    assert!(set.swap_remove(&1));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [0, 5, 2, 3, 4]);
+    assert!(!set.swap_remove(&1));
+    assert_eq!(set.swap_take(&0), Some(0));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [4, 5, 2, 3]);
+
+    assert!(set.shift_remove(&5));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [4, 2, 3]);
+    assert_eq!(set.shift_take(&4), Some(4));
+    assert_eq!(set.shift_take(&4), None);
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [2, 3]);
+
+    assert_eq!(set.shift_remove_index(0), Some(2));
+    assert_eq!(set.shift_remove_index(1), None);
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [3]);
+}
+
+#[test]
+fn test_extract_if() {
+    let mut set: VecSet<i32> = (0..8).collect();
+
+    let extracted: Vec<_> = set.extract_if(|v| v % 2 == 0).collect();
+    assert_eq!(extracted, [0, 2, 4, 6]);
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 3, 5, 7]);
+
+    // Dropping the iterator early keeps the values it hasn't visited
+    assert_eq!(set.extract_if(|_| true).next(), Some(1));
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), [3, 5, 7]);
+
+    let mut set: VecSet<String> = (0..6).map(|x| x.to_string()).collect();
+    let mut iter = set.extract_if(|v| v != "2");
+    assert_eq!(iter.next().as_deref(), Some("0"));
+    assert_eq!(iter.next().as_deref(), Some("1"));
+    assert_eq!(iter.next().as_deref(), Some("3"));
+    drop(iter);
+    assert_eq!(
+        set.iter().map(String::as_str).collect::<Vec<_>>(),
+        ["2", "4", "5"]
+    );
+}
+
+#[test]
+fn test_boxed_storage() {
+    let boxed: VecSet<i32, Box<[i32]>> = VecSet::with_storage(Box::new([7, 7, 7]));
+    assert!(boxed.is_empty());
+    let mut boxed: VecSet<i32, Box<[i32]>> = [3, 4, 5, 4].into_iter().collect();
+    assert_eq!(boxed.capacity(), 3);
+    assert!(boxed.remove(&4));
+    assert_eq!(boxed.drain().collect::<Vec<_>>(), [3, 5]);
+}
+
+#[test]
+#[cfg(feature = "arrayvec")]
+fn test_other_storages() {
+    let mut inline: VecSet<i32, ArrayVec<i32, 4>> = [1, 2, 3].into_iter().collect();
+    assert!(!inline.insert(2));
+    assert!(inline.insert(4));
+    assert_eq!(inline.capacity(), 4);
+
+    let boxed: VecSet<i32, Box<[i32]>> = VecSet::with_storage(Box::new([7, 7, 7]));
+    assert!(boxed.is_empty());
+    let boxed: VecSet<i32, Box<[i32]>> = [3, 4, 5, 6].into_iter().collect();
+
+    let set: VecSet<i32> = (0..3).collect();
+    assert_eq!(
+        inline.intersection(&boxed).copied().collect::<Vec<_>>(),
+        [3, 4]
+    );
+    assert_eq!(inline.difference(&set).copied().collect::<Vec<_>>(), [3, 4]);
+    assert_eq!(
+        set.union(&inline).copied().collect::<Vec<_>>(),
+        [1, 2, 3, 4, 0]
+    );
+    assert!(set.is_disjoint(&boxed));
+    assert!(!inline.is_subset(&boxed));
+
+    assert!(inline.remove(&1));
+    assert_eq!(inline.drain().collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(boxed.into_iter().collect::<Vec<_>>(), [3, 4, 5, 6]);
+}
+
+#[test]
+fn test_equivalent_lookup() {
+    use crate::equivalent::Equivalent;
+
+    #[derive(PartialEq, Eq)]
+    enum Shape {
+        Circle(u32),
+        Square(u32),
+    }
+
+    #[derive(PartialEq, Eq)]
+    enum Kind {
+        Circle,
+        Square,
+    }
+
+    impl Equivalent<Shape> for Kind {
+        fn equivalent(&self, key: &Shape) -> bool {
+            matches!(
+                (self, key),
+                (Kind::Circle, Shape::Circle(_)) | (Kind::Square, Shape::Square(_))
+            )
+        }
+    }
+
+    let mut set = VecSet::from([Shape::Circle(1), Shape::Square(2)]);
+    assert!(set.contains(&Kind::Square));
+    assert!(set.get(&Kind::Circle) == Some(&Shape::Circle(1)));
+    assert_eq!(set.get_index_of(&Kind::Square), Some(1));
+    assert!(set.shift_take(&Kind::Circle) == Some(Shape::Circle(1)));
+    assert!(!set.remove(&Kind::Circle));
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn test_hash_ignores_order() {
+    use std::collections::HashMap;
+    use std::hash::{BuildHasher, RandomState};
+
+    let state = RandomState::new();
+    let a = VecSet::from(["x", "y", "z"]);
+    let b = VecSet::from(["z", "x", "y"]);
+    assert_eq!(a, b);
+    assert_eq!(state.hash_one(&a), state.hash_one(&b));
+    // A subset isn't equal, and hashes differently
+    let subset = VecSet::from(["x", "y"]);
+    assert_ne!(a, subset);
+    assert_ne!(subset, a);
+    assert_ne!(state.hash_one(&a), state.hash_one(&subset));
+    assert_ne!(
+        state.hash_one(&a),
+        state.hash_one(VecSet::from(["x", "y", "w"]))
+    );
+
+    let mut tagged = HashMap::new();
+    tagged.insert(a, 1);
+    tagged.insert(b, 2);
+    assert_eq!(tagged.len(), 1);
+    assert_eq!(tagged[&VecSet::from(["y", "z", "x"])], 2);
+}
+
+#[test]
+fn test_eq_with_std_sets() {
+    use std::collections::BTreeSet;
+
+    let set: VecSet<i32> = (0..20).rev().collect();
+    let btree: BTreeSet<i32> = (0..20).collect();
+
+    assert!(set == btree);
+    assert!(btree == set);
+
+    let longer: BTreeSet<i32> = (0..21).collect();
+    assert!(set != longer);
+    assert!(longer != set);
+    assert!(VecSet::from([1, 2]) != VecSet::from([1, 2, 3]));
+    assert!(VecSet::from([1, 2, 3]) != VecSet::from([1, 2]));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_eq_with_hash_sets() {
+    use std::collections::HashSet;
+
+    let set: VecSet<i32> = (0..20).rev().collect();
+    let hash: HashSet<i32> = (0..20).collect();
+
+    assert!(set == hash);
+    assert!(hash == set);
+
+    let shorter: HashSet<i32> = (0..19).collect();
+    assert!(set != shorter);
+    assert!(shorter != set);
+}
+
+#[test]
+fn test_std_conversions() {
+    use std::collections::BTreeSet;
+    use std::vec;
+
+    let set = VecSet::from(vec![3, 1, 3, 2, 1]);
+    assert_eq!(Vec::from(set.clone()), [3, 1, 2]);
+
+    let btree: BTreeSet<_> = set.into();
+    let sorted = VecSet::from(btree.clone());
+    assert_eq!(Vec::from(sorted.clone()), [1, 2, 3]);
+    assert_eq!(BTreeSet::from(sorted), btree);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_hash_set_conversions() {
+    use std::collections::HashSet;
+
+    let set = VecSet::from([3, 1, 2]);
+    let hash: HashSet<_> = set.clone().into();
+    assert_eq!(hash, HashSet::from([1, 2, 3]));
+    assert_eq!(VecSet::from(hash), set);
+}
+
+#[test]
+#[cfg(feature = "arrayvec")]
+fn test_try_insert_and_take() {
+    let mut set: VecSet<i32, ArrayVec<i32, 2>> = VecSet::default();
+    assert_eq!(set.try_insert(1), Ok(true));
+    assert_eq!(set.try_insert(2), Ok(true));
+    assert_eq!(set.try_insert(1), Ok(false));
+    assert_eq!(set.try_insert(3).unwrap_err().element(), 3);
+
+    assert_eq!(set.take(&1), Some(1));
+    assert_eq!(set.take(&1), None);
+    assert_eq!(set.try_insert(3), Ok(true));
+}
@@ -0,0 +1,24 @@
+use smallvec::SmallVec;
+
+use crate::map::VecMap;
+
+///A [`VecMap`] that stores up to `N` entries inline, and only spills over onto the heap once it grows past that.
+///
+///All the lookup, entry and iteration methods are shared with [`VecMap`]. Create one with [`Default::default`] or
+///[`FromIterator`].
+pub type SmallVecMap<K, V, const N: usize> = VecMap<K, V, SmallVec<[(K, V); N]>>;
+
+impl<K, V, const N: usize> SmallVecMap<K, V, N> {
+    ///Returns `true` if the entries are still stored inline, `false` if the map has spilled over onto the heap
+    pub fn is_inline(&self) -> bool {
+        !self.vec.spilled()
+    }
+
+    ///Returns the number of entries the map can hold before spilling over onto the heap
+    pub const fn inline_size(&self) -> usize {
+        N
+    }
+}
+
+#[cfg(test)]
+mod tests;
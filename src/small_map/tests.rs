@@ -0,0 +1,60 @@
+use crate::small_map::*;
+use std::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[test]
+fn test_spills_past_inline_capacity() {
+    let mut m: SmallVecMap<i32, char, 2> = SmallVecMap::default();
+    assert_eq!(m.inline_size(), 2);
+    m.insert(1, 'a');
+    m.insert(2, 'b');
+    assert!(m.is_inline());
+    assert_eq!(m.insert(1, 'x'), Some('a'));
+    assert!(m.is_inline());
+
+    m.insert(3, 'c');
+    assert!(!m.is_inline());
+    assert_eq!(m.len(), 3);
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(m.get(&1), Some(&'x'));
+    assert_eq!(m.remove(&2), Some('b'));
+    assert_eq!(m.iter().collect::<Vec<_>>(), [(&1, &'x'), (&3, &'c')]);
+}
+
+#[test]
+fn test_shared_api() {
+    let mut m: SmallVecMap<String, i32, 4> = [("a", 1), ("b", 2), ("c", 3)]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+
+    *m.entry("a".to_string()).or_insert(0) += 10;
+    *m.entry_ref("d").or_insert(4) += 0;
+    assert_eq!(m["a"], 11);
+    assert_eq!(m.entry("d".to_string()).index(), 3);
+
+    let extracted: Vec<_> = m.extract_if(|_, v| *v % 2 == 0).collect();
+    assert_eq!(extracted, [("b".to_string(), 2), ("d".to_string(), 4)]);
+
+    m.retain(|k, _| k != "c");
+    assert_eq!(m.drain().collect::<Vec<_>>(), [("a".to_string(), 11)]);
+    assert!(m.is_empty());
+    assert!(m.is_inline());
+}
+
+#[test]
+fn test_into_iter_and_eq() {
+    let a: SmallVecMap<i32, i32, 2> = (0..5).map(|i| (i, i * i)).collect();
+    let b: SmallVecMap<i32, i32, 2> = (0..5).rev().map(|i| (i, i * i)).collect();
+    assert_eq!(a, b);
+    assert_eq!(
+        a.clone().into_values().collect::<Vec<_>>(),
+        [0, 1, 4, 9, 16]
+    );
+    assert_eq!(
+        b.into_iter().map(|(k, _)| k).collect::<Vec<_>>(),
+        [4, 3, 2, 1, 0]
+    );
+}
@@ -0,0 +1,600 @@
+use alloc::{collections::TryReserveError, vec::Vec};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::{Bound, Index, RangeBounds},
+};
+
+use crate::{
+    map::{
+        Drain, ExtractIf, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut,
+    },
+    storage::Extractor,
+};
+
+#[cfg(test)]
+mod tests;
+
+///A map that keeps its entries sorted by key, using binary search for lookups instead of the linear search used by
+///[`crate::map::VecMap`]. Has a similar API to [`std::collections::BTreeMap`].
+///
+///Iteration order is always the order of the keys. Lookups are O(log n), insertions and removals are O(n), since
+///the following entries have to be shifted.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct SortedVecMap<K, V> {
+    vec: Vec<(K, V)>,
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug> core::fmt::Debug for SortedVecMap<K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(self.vec.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
+pub struct VacantEntry<'a, K, V> {
+    key: K,
+    ///Index the entry will be inserted at to keep the map sorted
+    index: usize,
+    table: &'a mut Vec<(K, V)>,
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    ///Returns the index the entry will have once inserted
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.table.insert(self.index, (self.key, value));
+        &mut self.table[self.index].1
+    }
+}
+
+impl<K: core::fmt::Debug, V> core::fmt::Debug for VacantEntry<'_, K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("VacantEntry").field(self.key()).finish()
+    }
+}
+
+pub struct OccupiedEntry<'a, K, V> {
+    index: usize,
+    table: &'a mut Vec<(K, V)>,
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    pub fn get(&self) -> &V {
+        &self.table[self.index].1
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.table[self.index].1
+    }
+
+    ///Sets the value of the entry and returns the old value
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.table[self.index].1
+    }
+
+    pub fn key(&self) -> &K {
+        &self.table[self.index].0
+    }
+
+    ///Returns the index of the entry in the map
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    pub fn remove_entry(self) -> (K, V) {
+        self.table.remove(self.index)
+    }
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug> core::fmt::Debug for OccupiedEntry<'_, K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => e.insert(default),
+        }
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => e.insert(default()),
+        }
+    }
+
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => {
+                let value = default(&e.key);
+                e.insert(value)
+            }
+        }
+    }
+
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(e) => e.key(),
+            Entry::Vacant(e) => e.key(),
+        }
+    }
+
+    ///Returns the index of the entry, or the index it will have once inserted if it's vacant
+    pub fn index(&self) -> usize {
+        match self {
+            Entry::Occupied(e) => e.index(),
+            Entry::Vacant(e) => e.index(),
+        }
+    }
+
+    #[must_use]
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Entry::Occupied(mut e) => {
+                f(e.get_mut());
+                Entry::Occupied(e)
+            }
+            Entry::Vacant(_) => self,
+        }
+    }
+}
+
+impl<'a, K, V> Entry<'a, K, V>
+where
+    V: Default,
+{
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert(V::default())
+    }
+}
+
+impl<K, V> SortedVecMap<K, V>
+where
+    K: Ord,
+{
+    ///Creates an empty `SortedVecMap`
+    pub const fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+
+    ///Creates an empty `SortedVecMap` with at least the specified capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: Vec::with_capacity(capacity),
+        }
+    }
+
+    ///Returns the number of elements the map can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.vec.try_reserve(additional)
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit();
+    }
+
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.vec.shrink_to(min_capacity);
+    }
+
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    ///Binary searches the map for the given key, returning the index of the entry if it's found, or the index
+    ///where it would have to be inserted to keep the map sorted otherwise
+    fn search<Q>(&self, k: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.vec.binary_search_by(|(key, _)| key.borrow().cmp(k))
+    }
+
+    ///Inserts a key-value pair into the map, at the position given by the order of the keys.
+    ///
+    ///If the map already had this key present, the value is updated, and the old value is returned. The key is not
+    ///updated.
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        match self.search(&k) {
+            Ok(i) => Some(core::mem::replace(&mut self.vec[i].1, v)),
+            Err(i) => {
+                self.vec.insert(i, (k, v));
+                None
+            }
+        }
+    }
+
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(k).map(|i| i.1)
+    }
+
+    pub fn remove_entry<Q>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let i = self.search(k).ok()?;
+        Some(self.vec.remove(i))
+    }
+
+    pub fn get<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get_key_value(k).map(|i| i.1)
+    }
+
+    pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let i = self.search(k).ok()?;
+        Some(&mut self.vec[i].1)
+    }
+
+    pub fn get_key_value<Q>(&self, k: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let i = self.search(k).ok()?;
+        self.get_index(i)
+    }
+
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(k).is_ok()
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.search(&key) {
+            Ok(index) => Entry::Occupied(OccupiedEntry {
+                index,
+                table: &mut self.vec,
+            }),
+            Err(index) => Entry::Vacant(VacantEntry {
+                key,
+                index,
+                table: &mut self.vec,
+            }),
+        }
+    }
+
+    ///Returns the key-value pair at the given index, or `None` if the index is out of bounds
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.vec.get(index).map(|(k, v)| (k, v))
+    }
+
+    ///Returns the index of the given key, if it's present in the map
+    pub fn get_index_of<Q>(&self, k: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(k).ok()
+    }
+
+    ///Returns the key-value pair with the smallest key
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.vec.first().map(|(k, v)| (k, v))
+    }
+
+    ///Returns the key-value pair with the largest key
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.vec.last().map(|(k, v)| (k, v))
+    }
+
+    ///Removes and returns the key-value pair with the smallest key
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        if self.vec.is_empty() {
+            return None;
+        }
+        Some(self.vec.remove(0))
+    }
+
+    ///Removes and returns the key-value pair with the largest key
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        self.vec.pop()
+    }
+
+    ///Returns the index range of the entries whose keys are within the given range
+    fn range_indices<Q, R>(&self, range: &R) -> (usize, usize)
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        //Checked on the bounds themselves like `BTreeMap::range`, so that panicking doesn't depend on the keys
+        match (range.start_bound(), range.end_bound()) {
+            (Bound::Excluded(s), Bound::Excluded(e)) if s == e => {
+                panic!("range start and end are equal and excluded in SortedVecMap")
+            }
+            (Bound::Included(s) | Bound::Excluded(s), Bound::Included(e) | Bound::Excluded(e))
+                if s > e =>
+            {
+                panic!("range start is greater than range end in SortedVecMap")
+            }
+            _ => {}
+        }
+        let start = match range.start_bound() {
+            Bound::Included(s) => self.vec.partition_point(|(k, _)| k.borrow() < s),
+            Bound::Excluded(s) => self.vec.partition_point(|(k, _)| k.borrow() <= s),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(e) => self.vec.partition_point(|(k, _)| k.borrow() <= e),
+            Bound::Excluded(e) => self.vec.partition_point(|(k, _)| k.borrow() < e),
+            Bound::Unbounded => self.vec.len(),
+        };
+        (start, end)
+    }
+
+    ///Returns an iterator over the entries whose keys are within the given range, in key order
+    ///
+    ///Panics if the start of the range is greater than its end, or if both bounds are excluded and equal
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let (start, end) = self.range_indices(&range);
+        Iter {
+            inner: self.vec[start..end].iter(),
+        }
+    }
+
+    ///Returns a mutable iterator over the entries whose keys are within the given range, in key order
+    ///
+    ///Panics if the start of the range is greater than its end, or if both bounds are excluded and equal
+    pub fn range_mut<Q, R>(&mut self, range: R) -> IterMut<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let (start, end) = self.range_indices(&range);
+        IterMut {
+            inner: self.vec[start..end].iter_mut(),
+        }
+    }
+
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys {
+            inner: self.vec.iter(),
+        }
+    }
+
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        IntoKeys {
+            inner: self.vec.into_iter(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values {
+            inner: self.vec.iter(),
+        }
+    }
+
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            inner: self.vec.iter_mut(),
+        }
+    }
+
+    pub fn into_values(self) -> IntoValues<K, V> {
+        IntoValues {
+            inner: self.vec.into_iter(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.vec.iter(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: self.vec.iter_mut(),
+        }
+    }
+
+    pub fn drain(&mut self) -> Drain<'_, K, V> {
+        Drain {
+            inner: self.vec.drain(..),
+        }
+    }
+
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let mut f = f;
+        self.vec.retain_mut(|i| f(&i.0, &mut i.1));
+    }
+
+    ///Creates an iterator that removes and yields all entries for which `pred` returns `true`, in key order.
+    ///
+    ///If the iterator is dropped before being exhausted, all entries that weren't visited yet are kept in the map
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        ExtractIf {
+            inner: Extractor::new(&mut self.vec),
+            pred,
+        }
+    }
+}
+
+impl<K, V> Extend<(K, V)> for SortedVecMap<K, V>
+where
+    K: Ord,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<'a, K, V> Extend<(&'a K, &'a V)> for SortedVecMap<K, V>
+where
+    K: Ord + Copy,
+    V: Copy,
+{
+    fn extend<T: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: T) {
+        for (k, v) in iter {
+            self.insert(*k, *v);
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for SortedVecMap<K, V>
+where
+    K: Ord,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut vec: Vec<(K, V)> = iter.into_iter().collect();
+        //Stable sort keeps duplicates in insertion order, so the last value wins, like with repeated inserts
+        vec.sort_by(|a, b| a.0.cmp(&b.0));
+        vec.dedup_by(|later, kept| {
+            if later.0.cmp(&kept.0) == Ordering::Equal {
+                core::mem::swap(&mut later.1, &mut kept.1);
+                true
+            } else {
+                false
+            }
+        });
+        Self { vec }
+    }
+}
+
+impl<K, V, const N: usize> From<[(K, V); N]> for SortedVecMap<K, V>
+where
+    K: Ord,
+{
+    fn from(value: [(K, V); N]) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<K, Q, V> Index<&Q> for SortedVecMap<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    fn index(&self, index: &Q) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<K, V> IntoIterator for SortedVecMap<K, V>
+where
+    K: Eq,
+{
+    type Item = (K, V);
+
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.vec.into_iter(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a SortedVecMap<K, V> {
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            inner: self.vec.iter(),
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut SortedVecMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            inner: self.vec.iter_mut(),
+        }
+    }
+}
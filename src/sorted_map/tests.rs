@@ -0,0 +1,174 @@
+use crate::sorted_map::*;
+use std::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[test]
+fn test_insert_keeps_sorted() {
+    let mut m = SortedVecMap::new();
+    assert!(m.insert(3, 'c').is_none());
+    assert!(m.insert(1, 'a').is_none());
+    assert!(m.insert(2, 'b').is_none());
+    assert_eq!(m.insert(1, 'x'), Some('a'));
+
+    assert_eq!(m.len(), 3);
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(m.values().copied().collect::<Vec<_>>(), ['x', 'b', 'c']);
+}
+
+#[test]
+fn test_get_and_remove() {
+    let mut m: SortedVecMap<String, i32> = SortedVecMap::new();
+    m.insert("b".to_string(), 2);
+    m.insert("a".to_string(), 1);
+
+    assert_eq!(m.get("a"), Some(&1));
+    assert_eq!(m.get("c"), None);
+    assert!(m.contains_key("b"));
+    assert_eq!(m["b"], 2);
+    *m.get_mut("b").unwrap() = 20;
+    assert_eq!(m.get_key_value("b"), Some((&"b".to_string(), &20)));
+    assert_eq!(m.get_index_of("b"), Some(1));
+
+    assert_eq!(m.remove("a"), Some(1));
+    assert_eq!(m.remove("a"), None);
+    assert_eq!(m.remove_entry("b"), Some(("b".to_string(), 20)));
+    assert!(m.is_empty());
+}
+
+#[test]
+fn test_from_iter_last_wins() {
+    let m: SortedVecMap<_, _> = [(2, 'b'), (1, 'a'), (2, 'c'), (3, 'd'), (1, 'e')]
+        .into_iter()
+        .collect();
+
+    assert_eq!(
+        m.into_iter().collect::<Vec<_>>(),
+        [(1, 'e'), (2, 'c'), (3, 'd')]
+    );
+    assert_eq!(
+        SortedVecMap::from([(2, 'b'), (1, 'a')]),
+        SortedVecMap::from([(1, 'a'), (2, 'b')])
+    );
+}
+
+#[test]
+fn test_range() {
+    let mut m: SortedVecMap<i32, i32> = (0..10).map(|i| (i * 2, i)).collect();
+
+    let keys = |it: crate::map::Iter<'_, i32, i32>| it.map(|(k, _)| *k).collect::<Vec<_>>();
+    assert_eq!(keys(m.range(3..9)), [4, 6, 8]);
+    assert_eq!(keys(m.range(4..=8)), [4, 6, 8]);
+    assert_eq!(keys(m.range(..4)), [0, 2]);
+    assert_eq!(keys(m.range(15..)), [16, 18]);
+    assert_eq!(keys(m.range(5..5)), []);
+    assert_eq!(keys(m.range(..)).len(), 10);
+
+    for (_, v) in m.range_mut(10..) {
+        *v = 0;
+    }
+    assert_eq!(m.values().filter(|v| **v == 0).count(), 6);
+}
+
+#[test]
+#[should_panic]
+fn test_range_backwards() {
+    let m: SortedVecMap<i32, i32> = (0..10).map(|i| (i, i)).collect();
+    let (start, end) = (7, 3);
+    _ = m.range(start..end);
+}
+
+#[test]
+#[should_panic]
+fn test_range_backwards_without_keys() {
+    let m: SortedVecMap<i32, i32> = (10..20).map(|i| (i, i)).collect();
+    let (start, end) = (5, 3);
+    _ = m.range(start..end);
+}
+
+#[test]
+#[should_panic]
+fn test_range_backwards_empty() {
+    let mut m: SortedVecMap<i32, i32> = SortedVecMap::new();
+    let (start, end) = (5, 3);
+    _ = m.range_mut(start..end);
+}
+
+#[test]
+#[should_panic]
+fn test_range_excluded_equal() {
+    let m = SortedVecMap::from([(0, 'a'), (1, 'b'), (2, 'c')]);
+    _ = m.range((Bound::Excluded(1), Bound::Excluded(1)));
+}
+
+#[test]
+#[should_panic]
+fn test_range_excluded_equal_without_keys() {
+    let m = SortedVecMap::from([(5, 'a'), (6, 'b')]);
+    _ = m.range((Bound::Excluded(1), Bound::Excluded(1)));
+}
+
+#[test]
+fn test_first_last() {
+    let mut m = SortedVecMap::from([(5, 'e'), (1, 'a'), (3, 'c')]);
+
+    assert_eq!(m.first_key_value(), Some((&1, &'a')));
+    assert_eq!(m.last_key_value(), Some((&5, &'e')));
+    assert_eq!(m.pop_first(), Some((1, 'a')));
+    assert_eq!(m.pop_last(), Some((5, 'e')));
+    assert_eq!(m.pop_last(), Some((3, 'c')));
+    assert_eq!(m.pop_first(), None);
+    assert_eq!(m.first_key_value(), None);
+}
+
+#[test]
+fn test_entry() {
+    let mut m = SortedVecMap::from([(1, 10), (5, 50)]);
+
+    match m.entry(3) {
+        Entry::Occupied(_) => unreachable!(),
+        Entry::Vacant(e) => {
+            assert_eq!(e.index(), 1);
+            assert_eq!(*e.insert(30), 30);
+        }
+    }
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 3, 5]);
+
+    match m.entry(5) {
+        Entry::Vacant(_) => unreachable!(),
+        Entry::Occupied(mut e) => {
+            assert_eq!(e.index(), 2);
+            assert_eq!(e.insert(500), 50);
+        }
+    }
+    assert_eq!(m[&5], 500);
+
+    *m.entry(0).or_default() += 1;
+    *m.entry(1).and_modify(|v| *v += 1).or_insert(0) += 1;
+    assert_eq!(
+        m.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+        [(0, 1), (1, 12), (3, 30), (5, 500)]
+    );
+
+    match m.entry(3) {
+        Entry::Vacant(_) => unreachable!(),
+        Entry::Occupied(e) => assert_eq!(e.remove_entry(), (3, 30)),
+    }
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [0, 1, 5]);
+}
+
+#[test]
+fn test_retain_and_extract_if() {
+    let mut m: SortedVecMap<i32, i32> = (0..10).rev().map(|i| (i, i)).collect();
+
+    m.retain(|k, _| k % 3 != 0);
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 2, 4, 5, 7, 8]);
+
+    let odd: Vec<_> = m.extract_if(|k, _| k % 2 == 1).map(|(k, _)| k).collect();
+    assert_eq!(odd, [1, 5, 7]);
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [2, 4, 8]);
+
+    assert_eq!(m.drain().count(), 3);
+    assert!(m.is_empty());
+}
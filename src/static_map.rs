@@ -0,0 +1,156 @@
+use core::ops::Index;
+
+use crate::{
+    equivalent::Equivalent,
+    map::{Iter, Keys, Values},
+};
+
+#[cfg(test)]
+mod tests;
+
+///A read-only map over a `&'static` slice of entries, meant for constant lookup tables that can live in `static`
+///items without any runtime construction.
+///
+///The entries are used as given, iteration follows the order of the slice. If the slice contains duplicate keys, the
+///lookups return the first entry with that key.
+pub struct StaticVecMap<K: 'static, V: 'static> {
+    entries: &'static [(K, V)],
+}
+
+impl<K, V> Clone for StaticVecMap<K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V> Copy for StaticVecMap<K, V> {}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug> core::fmt::Debug for StaticVecMap<K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(self.entries.iter().map(|(k, v)| (k, v)))
+            .finish()
+    }
+}
+
+impl<K, V> StaticVecMap<K, V> {
+    ///Creates a map over the given entries
+    pub const fn new(entries: &'static [(K, V)]) -> Self {
+        Self { entries }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    ///Returns the underlying slice of entries
+    pub const fn as_slice(&self) -> &'static [(K, V)] {
+        self.entries
+    }
+
+    pub fn iter(&self) -> Iter<'static, K, V> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+
+    pub fn keys(&self) -> Keys<'static, K, V> {
+        Keys {
+            inner: self.entries.iter(),
+        }
+    }
+
+    pub fn values(&self) -> Values<'static, K, V> {
+        Values {
+            inner: self.entries.iter(),
+        }
+    }
+
+    ///Returns the key-value pair at the given index, or `None` if the index is out of bounds
+    pub fn get_index(&self, index: usize) -> Option<(&'static K, &'static V)> {
+        self.entries.get(index).map(|(k, v)| (k, v))
+    }
+
+    ///Returns the first key-value pair in the map
+    pub fn first(&self) -> Option<(&'static K, &'static V)> {
+        self.entries.first().map(|(k, v)| (k, v))
+    }
+
+    ///Returns the last key-value pair in the map
+    pub fn last(&self) -> Option<(&'static K, &'static V)> {
+        self.entries.last().map(|(k, v)| (k, v))
+    }
+}
+
+impl<K, V> StaticVecMap<K, V>
+where
+    K: Eq,
+{
+    pub fn get<Q>(&self, k: &Q) -> Option<&'static V>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(k).map(|(_, v)| v)
+    }
+
+    pub fn get_key_value<Q>(&self, k: &Q) -> Option<(&'static K, &'static V)>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.entries
+            .iter()
+            .find(|(key, _)| k.equivalent(key))
+            .map(|(k, v)| (k, v))
+    }
+
+    ///Returns the index of the given key, if it's present in the map
+    pub fn get_index_of<Q>(&self, k: &Q) -> Option<usize>
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.entries.iter().position(|(key, _)| k.equivalent(key))
+    }
+
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        Q: Equivalent<K> + ?Sized,
+    {
+        self.get_index_of(k).is_some()
+    }
+}
+
+impl<K, Q, V> Index<&Q> for StaticVecMap<K, V>
+where
+    K: Eq,
+    Q: Equivalent<K> + ?Sized,
+{
+    type Output = V;
+
+    fn index(&self, index: &Q) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<K, V> IntoIterator for StaticVecMap<K, V> {
+    type Item = (&'static K, &'static V);
+
+    type IntoIter = Iter<'static, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K, V> IntoIterator for &StaticVecMap<K, V> {
+    type Item = (&'static K, &'static V);
+
+    type IntoIter = Iter<'static, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,48 @@
+use std::vec::Vec;
+
+use crate::static_map::*;
+
+static ERRORS: StaticVecMap<u16, &str> = StaticVecMap::new(&[
+    (404, "Not Found"),
+    (500, "Internal Server Error"),
+    (418, "I'm a teapot"),
+]);
+
+const OPCODES: StaticVecMap<&str, u8> =
+    StaticVecMap::new(&[("nop", 0x90), ("ret", 0xc3), ("nop", 0)]);
+const OPCODE_COUNT: usize = OPCODES.len();
+
+#[test]
+fn test_lookup() {
+    assert_eq!(ERRORS.get(&404), Some(&"Not Found"));
+    assert_eq!(ERRORS.get(&200), None);
+    assert!(ERRORS.contains_key(&418));
+    assert_eq!(ERRORS[&500], "Internal Server Error");
+    assert_eq!(ERRORS.get_index_of(&418), Some(2));
+    assert_eq!(ERRORS.get_key_value(&418), Some((&418, &"I'm a teapot")));
+
+    // The first entry wins for duplicate keys
+    assert_eq!(OPCODES.get("nop"), Some(&0x90));
+    assert_eq!(OPCODE_COUNT, 3);
+    assert!(!OPCODES.is_empty());
+}
+
+#[test]
+fn test_iteration_order() {
+    assert_eq!(ERRORS.keys().copied().collect::<Vec<_>>(), [404, 500, 418]);
+    assert_eq!(
+        OPCODES.values().copied().collect::<Vec<_>>(),
+        [0x90, 0xc3, 0]
+    );
+    assert_eq!(ERRORS.iter().len(), 3);
+    assert_eq!(ERRORS.first(), Some((&404, &"Not Found")));
+    assert_eq!(ERRORS.last(), Some((&418, &"I'm a teapot")));
+    assert_eq!(ERRORS.get_index(1), Some((&500, &"Internal Server Error")));
+
+    let mut count = 0;
+    for (k, _) in ERRORS {
+        assert!(ERRORS.contains_key(k));
+        count += 1;
+    }
+    assert_eq!(count, ERRORS.len());
+}
@@ -0,0 +1,494 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+};
+
+#[cfg(feature = "arrayvec")]
+use arrayvec::ArrayVec;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+
+///The error returned when inserting into a storage that is full and can't grow, holding the rejected element
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CapacityError<T> {
+    element: T,
+}
+
+impl<T> CapacityError<T> {
+    ///Creates a new `CapacityError` holding `element`
+    pub const fn new(element: T) -> Self {
+        Self { element }
+    }
+
+    ///Returns the element that couldn't be inserted
+    pub fn element(self) -> T {
+        self.element
+    }
+}
+
+impl<T> Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CapacityError: insufficient capacity")
+    }
+}
+
+impl<T> Display for CapacityError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "insufficient capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for CapacityError<T> {}
+
+///The backing storage of a [`crate::map::VecMap`] or a [`crate::set::VecSet`], a contiguous growable list of
+///elements.
+///
+///Implemented for [`Vec`] and [`Box<[T]>`], and for `SmallVec` and `ArrayVec` with the `smallvec` and `arrayvec`
+///features. Implementing it for another type allows using it as the backend of a map or a set, while reusing all of
+///the lookup, entry, iteration and set algebra logic.
+///
+///# Safety
+///
+///The in place removal of the `extract_if` iterators reads and moves the elements through raw pointers, so
+///implementers must guarantee that:
+///
+///- [`Storage::as_slice`] and [`Storage::as_mut_slice`] return the first `len` elements of the buffer pointed to by
+///  [`Storage::as_mut_ptr`], where `len` is the current length of the storage
+///- the buffer returned by [`Storage::as_mut_ptr`] is valid for [`Storage::capacity`] elements, and doesn't move
+///  unless the storage is mutated through another method
+///- [`Storage::set_len`] only returns `true` if it actually set the length to `len`, and never drops, moves or
+///  initializes any element
+pub unsafe trait Storage<T>: IntoIterator<Item = T, IntoIter: ExactSizeIterator> {
+    ///Iterator returned by [`Storage::drain`]
+    type Drain<'a>: ExactSizeIterator<Item = T>
+    where
+        Self: 'a;
+
+    fn as_slice(&self) -> &[T];
+
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    ///Returns the number of elements the storage can hold without reallocating
+    fn capacity(&self) -> usize;
+
+    ///Reserves capacity for at least `additional` more elements
+    fn reserve(&mut self, additional: usize);
+
+    ///Appends an element to the end of the storage
+    fn push(&mut self, value: T);
+
+    ///Appends an element to the end of the storage, or returns it in a [`CapacityError`] if the storage is full and
+    ///can't grow
+    fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>>;
+
+    ///Inserts an element at `index`, shifting all elements after it to the right
+    fn insert(&mut self, index: usize, value: T);
+
+    ///Removes and returns the element at `index`, shifting all elements after it to the left
+    fn remove(&mut self, index: usize) -> T;
+
+    ///Removes and returns the element at `index`, replacing it with the last element
+    fn swap_remove(&mut self, index: usize) -> T;
+
+    fn pop(&mut self) -> Option<T>;
+
+    ///Retains only the elements for which `f` returns `true`, preserving their order
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F);
+
+    fn clear(&mut self);
+
+    ///Removes all elements from the storage, returning them as an iterator
+    fn drain(&mut self) -> Self::Drain<'_>;
+
+    ///Returns a raw pointer to the buffer of the storage, valid for [`Storage::capacity`] elements
+    fn as_mut_ptr(&mut self) -> *mut T;
+
+    ///Forces the length of the storage to `len` without dropping or initializing any element, like
+    ///[`Vec::set_len`]. Returns `false` without doing anything if the storage can't change its length in place.
+    ///
+    ///# Safety
+    ///
+    ///`len` must not be greater than [`Storage::capacity`], and the first `len` elements must be initialized
+    unsafe fn set_len(&mut self, len: usize) -> bool;
+}
+
+///Removes the elements matching a predicate one at a time while keeping the others in place, the shared
+///implementation of the `extract_if` iterators.
+///
+///Like [`Vec::extract_if`], the length of the storage is set to 0 while extracting, every kept element is shifted
+///back over the removed ones as it's visited, and the length is fixed up on drop. Leaking the extractor leaks the
+///elements instead of double dropping them. Storages that can't change their length in place fall back to removing
+///every extracted element on its own.
+pub(crate) struct Extractor<'a, T, S: Storage<T>> {
+    storage: &'a mut S,
+    //Index of the next element to visit
+    index: usize,
+    //Number of elements removed so far
+    removed: usize,
+    //Length of the storage before extracting, `None` if it's not changed in place
+    old_len: Option<usize>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, S: Storage<T>> Extractor<'a, T, S> {
+    pub(crate) fn new(storage: &'a mut S) -> Self {
+        let len = storage.as_slice().len();
+        //SAFETY: a length of 0 is always valid, the elements stay in the buffer until they are visited
+        let in_place = unsafe { storage.set_len(0) };
+        Self {
+            storage,
+            index: 0,
+            removed: 0,
+            old_len: in_place.then_some(len),
+            _marker: PhantomData,
+        }
+    }
+
+    ///Returns the next element for which `pred` returns `true`, shifting back the kept elements it skips
+    pub(crate) fn next(&mut self, mut pred: impl FnMut(&mut T) -> bool) -> Option<T> {
+        let Some(old_len) = self.old_len else {
+            while let Some(v) = self.storage.as_mut_slice().get_mut(self.index) {
+                if pred(v) {
+                    return Some(self.storage.remove(self.index));
+                }
+                self.index += 1;
+            }
+            return None;
+        };
+        while self.index < old_len {
+            let ptr = self.storage.as_mut_ptr();
+            //SAFETY: elements from `index` to `old_len` haven't been visited yet, so they are still initialized, and
+            //the storage can't be accessed while we hold the mutable borrow
+            unsafe {
+                let current = ptr.add(self.index);
+                let matched = pred(&mut *current);
+                //Only counted as visited once `pred` returned, so it's kept if `pred` panics
+                self.index += 1;
+                if matched {
+                    self.removed += 1;
+                    return Some(core::ptr::read(current));
+                }
+                if self.removed > 0 {
+                    core::ptr::copy_nonoverlapping(current, current.sub(self.removed), 1);
+                }
+            }
+        }
+        None
+    }
+
+    ///Returns the number of elements that are left to visit
+    pub(crate) fn remaining(&self) -> usize {
+        self.old_len.unwrap_or(self.storage.as_slice().len()) - self.index
+    }
+}
+
+impl<T, S: Storage<T>> Drop for Extractor<'_, T, S> {
+    fn drop(&mut self) {
+        let Some(old_len) = self.old_len else {
+            return;
+        };
+        //SAFETY: the unvisited elements are moved right after the kept ones, so the first `old_len - removed`
+        //elements are initialized
+        unsafe {
+            if self.removed > 0 && self.index < old_len {
+                let ptr = self.storage.as_mut_ptr();
+                let src = ptr.add(self.index);
+                core::ptr::copy(src, src.sub(self.removed), old_len - self.index);
+            }
+            self.storage.set_len(old_len - self.removed);
+        }
+    }
+}
+
+//SAFETY: every method forwards to the inherent one, which views the same buffer
+unsafe impl<T> Storage<T> for Vec<T> {
+    type Drain<'a>
+        = alloc::vec::Drain<'a, T>
+    where
+        T: 'a;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        Vec::capacity(self)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+
+    fn push(&mut self, value: T) {
+        Vec::push(self, value);
+    }
+
+    fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        Vec::push(self, value);
+        Ok(())
+    }
+
+    fn insert(&mut self, index: usize, value: T) {
+        Vec::insert(self, index, value);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        Vec::remove(self, index)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        Vec::swap_remove(self, index)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        Vec::pop(self)
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        Vec::retain_mut(self, f);
+    }
+
+    fn clear(&mut self) {
+        Vec::clear(self);
+    }
+
+    fn drain(&mut self) -> Self::Drain<'_> {
+        Vec::drain(self, ..)
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        Vec::as_mut_ptr(self)
+    }
+
+    unsafe fn set_len(&mut self, len: usize) -> bool {
+        //SAFETY: upheld by the caller
+        unsafe { Vec::set_len(self, len) };
+        true
+    }
+}
+
+#[cfg(feature = "smallvec")]
+//SAFETY: every method forwards to the inherent one, which views the same buffer
+unsafe impl<T, const N: usize> Storage<T> for SmallVec<[T; N]> {
+    type Drain<'a>
+        = smallvec::Drain<'a, [T; N]>
+    where
+        T: 'a;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        SmallVec::capacity(self)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        SmallVec::reserve(self, additional);
+    }
+
+    fn push(&mut self, value: T) {
+        SmallVec::push(self, value);
+    }
+
+    fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        SmallVec::push(self, value);
+        Ok(())
+    }
+
+    fn insert(&mut self, index: usize, value: T) {
+        SmallVec::insert(self, index, value);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        SmallVec::remove(self, index)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        SmallVec::swap_remove(self, index)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        SmallVec::pop(self)
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        SmallVec::retain_mut(self, f);
+    }
+
+    fn clear(&mut self) {
+        SmallVec::clear(self);
+    }
+
+    fn drain(&mut self) -> Self::Drain<'_> {
+        SmallVec::drain(self, ..)
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        SmallVec::as_mut_ptr(self)
+    }
+
+    unsafe fn set_len(&mut self, len: usize) -> bool {
+        //SAFETY: upheld by the caller
+        unsafe { SmallVec::set_len(self, len) };
+        true
+    }
+}
+
+///Pushing into a full [`ArrayVec`] panics, [`Storage::reserve`] doesn't do anything.
+#[cfg(feature = "arrayvec")]
+//SAFETY: every method forwards to the inherent one, which views the same buffer
+unsafe impl<T, const N: usize> Storage<T> for ArrayVec<T, N> {
+    type Drain<'a>
+        = arrayvec::Drain<'a, T, N>
+    where
+        T: 'a;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn push(&mut self, value: T) {
+        ArrayVec::push(self, value);
+    }
+
+    fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        ArrayVec::try_push(self, value).map_err(|e| CapacityError::new(e.element()))
+    }
+
+    fn insert(&mut self, index: usize, value: T) {
+        ArrayVec::insert(self, index, value);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        ArrayVec::remove(self, index)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        ArrayVec::swap_remove(self, index)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        ArrayVec::pop(self)
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        ArrayVec::retain(self, f);
+    }
+
+    fn clear(&mut self) {
+        ArrayVec::clear(self);
+    }
+
+    fn drain(&mut self) -> Self::Drain<'_> {
+        ArrayVec::drain(self, ..)
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        ArrayVec::as_mut_ptr(self)
+    }
+
+    unsafe fn set_len(&mut self, len: usize) -> bool {
+        //SAFETY: upheld by the caller
+        unsafe { ArrayVec::set_len(self, len) };
+        true
+    }
+}
+
+///A boxed slice never has spare capacity, every insertion and removal reallocates it to the exact length, so it's
+///best suited for collections that are built once and then only read.
+//SAFETY: the slice methods view the same buffer, and `set_len` never changes the length
+unsafe impl<T> Storage<T> for Box<[T]> {
+    type Drain<'a>
+        = alloc::vec::IntoIter<T>
+    where
+        T: 'a;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn push(&mut self, value: T) {
+        with_vec(self, |v| v.push(value));
+    }
+
+    fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        Storage::push(self, value);
+        Ok(())
+    }
+
+    fn insert(&mut self, index: usize, value: T) {
+        with_vec(self, |v| v.insert(index, value));
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        with_vec(self, |v| v.remove(index))
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        with_vec(self, |v| v.swap_remove(index))
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        with_vec(self, Vec::pop)
+    }
+
+    fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+        with_vec(self, |v| v.retain_mut(f));
+    }
+
+    fn clear(&mut self) {
+        *self = Box::default();
+    }
+
+    fn drain(&mut self) -> Self::Drain<'_> {
+        core::mem::take(self).into_vec().into_iter()
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        <[T]>::as_mut_ptr(self)
+    }
+
+    ///A boxed slice has no spare capacity, so its length can't be changed in place
+    unsafe fn set_len(&mut self, _len: usize) -> bool {
+        false
+    }
+}
+
+///Runs `f` on the boxed slice converted into a [`Vec`], then converts it back
+fn with_vec<T, R>(b: &mut Box<[T]>, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+    let mut v = core::mem::take(b).into_vec();
+    let r = f(&mut v);
+    *b = v.into_boxed_slice();
+    r
+}